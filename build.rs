@@ -1,13 +1,14 @@
-extern crate cc;
-
 fn main() {
-    std::env::set_var("CC", "clang");
-
-    println!("cargo:rerun-if-changed=cpp");
+    #[cfg(feature = "cpp")]
+    {
+        println!("cargo:rerun-if-changed=cpp");
 
-    cc::Build::new()
-        .file("cpp/link.cc")
-        .flag("-std=c++17")
-        .opt_level(3)
-        .compile("float");
+        // The compiler comes from `CXX` or the platform default, as cc picks it.
+        cc::Build::new()
+            .cpp(true)
+            .std("c++17")
+            .file("cpp/link.cc")
+            .opt_level(3)
+            .compile("float");
+    }
 }
@@ -0,0 +1,66 @@
+use std::process::exit;
+
+use float::from_bytes::parse;
+use float_macros::{f32, f64};
+
+// Checks that the macros expand to the same bits `parse` gives at run time,
+// in constants as well as in expressions:
+//
+//     cargo run -p float-macros --bin literals
+
+const F64S: [(f64, &str); 12] = [
+    (f64!("0"), "0"),
+    (f64!("-0"), "-0"),
+    (f64!("0.1"), "0.1"),
+    (f64!("1.0000000000000000000001"), "1.0000000000000000000001"),
+    (f64!("1.00000000000000011102230246251565404236316680908203125"), "1.00000000000000011102230246251565404236316680908203125"),
+    (f64!("2.4703282292062328e-324"), "2.4703282292062328e-324"),
+    (f64!("1.7976931348623157e308", finite), "1.7976931348623157e308"),
+    (f64!("1e400"), "1e400"),
+    (f64!("1e2684354550"), "1e2684354550"),
+    (f64!("1e-2684354550"), "1e-2684354550"),
+    (f64!("0.5", exact), "0.5"),
+    (f64!(r"-0.000030517578125", exact), "-0.000030517578125"),
+];
+
+const F32S: [(f32, &str); 6] = [
+    (f32!("0.1"), "0.1"),
+    (f32!("16777217"), "16777217"),
+    (f32!("3.4028235e38", finite), "3.4028235e38"),
+    (f32!("1e-50"), "1e-50"),
+    (f32!("16777216", exact), "16777216"),
+    (f32!("1.40129846432481707092372958328991613128026194187651577175706828388979108268586060148663818836212158203125e-45", exact), "1.40129846432481707092372958328991613128026194187651577175706828388979108268586060148663818836212158203125e-45"),
+];
+
+macro_rules! forwarded {
+    ($lit:literal) => {
+        f64!($lit)
+    };
+}
+
+fn main() {
+    let mut fails = 0;
+    for (got, s) in F64S {
+        let want = parse::<f64>(s.as_bytes()).unwrap();
+        if got.to_bits() != want.to_bits() {
+            println!("f64!({:?}): got {:?}, want {:?}", s, got, want);
+            fails += 1;
+        }
+    }
+    for (got, s) in F32S {
+        let want = parse::<f32>(s.as_bytes()).unwrap();
+        if got.to_bits() != want.to_bits() {
+            println!("f32!({:?}): got {:?}, want {:?}", s, got, want);
+            fails += 1;
+        }
+    }
+    if forwarded!("2.5").to_bits() != 2.5f64.to_bits() {
+        println!("f64! through macro_rules: got {:?}", forwarded!("2.5"));
+        fails += 1;
+    }
+
+    println!("{} literals, {} failures", F64S.len() + F32S.len() + 1, fails);
+    if fails != 0 {
+        exit(1);
+    }
+}
@@ -0,0 +1,171 @@
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use float::from_bytes::{parse_exact, parse_with_limits, Error, Limits};
+
+// `f64!("0.1")` and `f32!("0.1")` expand to `f64::from_bits(..)` and
+// `f32::from_bits(..)` of the value `parse` rounds the literal to, so they
+// can be used in constants. The literal has to be a number and nothing
+// else. After it, `finite` also rejects literals that overflow to infinity,
+// and `exact` any literal the type cannot hold exactly:
+//
+//     const HALF: f32 = f32!("0.5", exact);
+
+#[proc_macro]
+pub fn f64(input: TokenStream) -> TokenStream {
+    expand(input, Type::F64)
+}
+
+#[proc_macro]
+pub fn f32(input: TokenStream) -> TokenStream {
+    expand(input, Type::F32)
+}
+
+#[derive(Clone, Copy)]
+enum Type {
+    F64,
+    F32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Check {
+    Syntax,
+    Finite,
+    Exact,
+}
+
+fn expand(input: TokenStream, ty: Type) -> TokenStream {
+    let (text, span, check) = match args(input) {
+        Ok(args) => args,
+        Err((span, msg)) => return compile_error(span, &msg),
+    };
+    match value(text.as_bytes(), ty, check) {
+        Ok(bits) => from_bits(ty, bits),
+        Err(err) => compile_error(span, &err.to_string()),
+    }
+}
+
+// The literal's text and span, and the check after it.
+fn args(input: TokenStream) -> Result<(String, Span, Check), (Span, String)> {
+    let mut tokens = input.into_iter();
+    let lit = match tokens.next().map(ungroup) {
+        Some(TokenTree::Literal(lit)) => lit,
+        Some(token) => return Err((token.span(), "expected a string literal".to_owned())),
+        None => return Err((Span::call_site(), "expected a string literal".to_owned())),
+    };
+    let text = match unquote(&lit.to_string()) {
+        Ok(text) => text,
+        Err(msg) => return Err((lit.span(), msg.to_owned())),
+    };
+
+    let mut check = Check::Syntax;
+    match tokens.next() {
+        None => {}
+        Some(TokenTree::Punct(p)) if p.as_char() == ',' => match tokens.next() {
+            None => {}
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "finite" => check = Check::Finite,
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "exact" => check = Check::Exact,
+            Some(token) => return Err((token.span(), "expected `finite` or `exact`".to_owned())),
+        },
+        Some(token) => return Err((token.span(), "expected `,`".to_owned())),
+    }
+    if let Some(token) = tokens.next() {
+        return Err((token.span(), "unexpected token".to_owned()));
+    }
+    Ok((text, lit.span(), check))
+}
+
+// A literal passed on through a `macro_rules!` macro arrives wrapped in an
+// invisible group.
+fn ungroup(token: TokenTree) -> TokenTree {
+    if let TokenTree::Group(group) = &token {
+        if group.delimiter() == Delimiter::None {
+            let mut inner = group.stream().into_iter();
+            if let (Some(only), None) = (inner.next(), inner.next()) {
+                return only;
+            }
+        }
+    }
+    token
+}
+
+// The contents of a string literal, raw or not. Numbers never need
+// escapes, so a literal with any is refused rather than decoded.
+fn unquote(lit: &str) -> Result<String, &'static str> {
+    let (quoted, raw) = match lit.strip_prefix('r') {
+        Some(raw) => {
+            let hashes = raw.len() - raw.trim_start_matches('#').len();
+            (raw.get(hashes..raw.len() - hashes), true)
+        }
+        None => (Some(lit), false),
+    };
+    match quoted.and_then(|q| q.strip_prefix('"')?.strip_suffix('"')) {
+        None => Err("expected a string literal"),
+        Some(text) if !raw && text.contains('\\') => Err("escapes are not allowed in a number"),
+        Some(text) => Ok(text.to_owned()),
+    }
+}
+
+fn value(d: &[u8], ty: Type, check: Check) -> Result<u64, Error> {
+    let whole = Limits {
+        max_len: usize::MAX,
+        max_digits: usize::MAX,
+        max_exponent: u32::MAX,
+    };
+    let (bits, infinite) = match (ty, check) {
+        (Type::F64, Check::Exact) => (parse_exact::<f64>(d)?.to_bits(), false),
+        (Type::F32, Check::Exact) => (parse_exact::<f32>(d)?.to_bits() as u64, false),
+        (Type::F64, _) => {
+            let f = parse_with_limits::<f64>(d, &whole)?;
+            (f.to_bits(), f.is_infinite())
+        }
+        (Type::F32, _) => {
+            let f = parse_with_limits::<f32>(d, &whole)?;
+            (f.to_bits() as u64, f.is_infinite())
+        }
+    };
+    if check == Check::Finite && infinite {
+        return Err(Error::Overflow);
+    }
+    Ok(bits)
+}
+
+// `::core::primitive::f64::from_bits(0x..u64)`, which is unaffected by
+// whatever `f64` means where the macro is used.
+fn from_bits(ty: Type, bits: u64) -> TokenStream {
+    let (name, lit) = match ty {
+        Type::F64 => ("f64", Literal::u64_suffixed(bits)),
+        Type::F32 => ("f32", Literal::u32_suffixed(bits as u32)),
+    };
+    let mut out = path(&["core", "primitive", name, "from_bits"], Span::call_site());
+    out.push(TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenTree::Literal(lit).into())));
+    out.into_iter().collect()
+}
+
+// `::core::compile_error! { "msg" }`, pointing at `span`.
+fn compile_error(span: Span, msg: &str) -> TokenStream {
+    let mut out = path(&["core", "compile_error"], span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    out.push(TokenTree::Punct(bang));
+    let mut lit = Literal::string(msg);
+    lit.set_span(span);
+    let mut body = Group::new(Delimiter::Brace, TokenTree::Literal(lit).into());
+    body.set_span(span);
+    out.push(TokenTree::Group(body));
+    out.into_iter().collect()
+}
+
+fn path(segments: &[&str], span: Span) -> Vec<TokenTree> {
+    let mut out = Vec::new();
+    for segment in segments {
+        for spacing in [Spacing::Joint, Spacing::Alone] {
+            let mut colon = Punct::new(':', spacing);
+            colon.set_span(span);
+            out.push(TokenTree::Punct(colon));
+        }
+        out.push(TokenTree::Ident(Ident::new(segment, span)));
+    }
+    out
+}
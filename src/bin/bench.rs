@@ -0,0 +1,188 @@
+extern crate float;
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use float::from_bytes::{Padded, PADDING};
+
+const N: usize = 4096;
+const ROUNDS: usize = 300;
+
+fn dataset(mut next: impl FnMut(u64) -> String) -> (Vec<u8>, Vec<(usize, usize)>) {
+    let mut x: u64 = 0x2545f4914f6cdd1d;
+    let mut buf = Vec::new();
+    let mut numbers = Vec::with_capacity(N);
+    for _ in 0..N {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        let start = buf.len();
+        buf.extend_from_slice(next(x).as_bytes());
+        numbers.push((start, buf.len() - start));
+        buf.push(b'\n');
+    }
+    buf.extend_from_slice(&[0; PADDING]);
+    (buf, numbers)
+}
+
+// Exact decimal expansion of the point halfway between `f` and the next
+// float up, which only the slow path can round. Both must be below 1.
+fn halfway(f: f64) -> String {
+    let lo = format!("{:.1100}", f);
+    let hi = format!("{:.1100}", f64::from_bits(f.to_bits() + 1));
+    let (lo, hi) = (&lo.as_bytes()[2..], &hi.as_bytes()[2..]);
+
+    let mut sum = vec![0; lo.len() + 1];
+    for i in (0..lo.len()).rev() {
+        let d = sum[i + 1] + (lo[i] - b'0') + (hi[i] - b'0');
+        sum[i + 1] = d % 10;
+        sum[i] = d / 10;
+    }
+
+    let mut out = String::from("0.");
+    let mut rem = sum[0];
+    for &d in &sum[1..] {
+        let d = rem * 10 + d;
+        out.push((b'0' + d / 2) as char);
+        rem = d % 2;
+    }
+    if rem != 0 {
+        out.push('5');
+    }
+    out.trim_end_matches('0').to_owned()
+}
+
+fn time(best: &mut f64, f: impl FnOnce() -> f64) {
+    let now = Instant::now();
+    black_box(f());
+    *best = best.min(now.elapsed().as_secs_f64());
+}
+
+// The two parsers take turns and each keeps its fastest round, which keeps
+// frequency changes and other load from favouring one of them.
+fn run(name: &str, buf: &[u8], numbers: &[(usize, usize)]) {
+    let mut plain = f64::MAX;
+    let mut padded = f64::MAX;
+    for _ in 0..ROUNDS {
+        time(&mut plain, || {
+            let mut sum = 0.0;
+            for &(start, len) in numbers {
+                sum += float::from_bytes::parse::<f64>(black_box(&buf[start..start + len])).unwrap();
+            }
+            sum
+        });
+        time(&mut padded, || {
+            let mut sum = 0.0;
+            for &(start, len) in numbers {
+                let d = Padded::new(black_box(&buf[start..]), len).unwrap();
+                sum += float::from_bytes::parse_padded::<f64>(d).unwrap();
+            }
+            sum
+        });
+    }
+
+    println!("{}", name);
+    for (name, elapsed) in [("parse", plain), ("parse_padded", padded)] {
+        println!(
+            "  {:14} {:8.1} MB/s {:6.1} ns/number",
+            name,
+            buf.len() as f64 / elapsed / 1e6,
+            elapsed * 1e9 / N as f64
+        );
+    }
+}
+
+fn run_f32(name: &str, buf: &[u8], numbers: &[(usize, usize)]) {
+    let mut best = f64::MAX;
+    for _ in 0..ROUNDS {
+        time(&mut best, || {
+            let mut sum = 0.0;
+            for &(start, len) in numbers {
+                sum += float::from_bytes::parse::<f32>(black_box(&buf[start..start + len])).unwrap();
+            }
+            sum as f64
+        });
+    }
+
+    println!("{}", name);
+    println!(
+        "  {:14} {:8.1} MB/s {:6.1} ns/number",
+        "parse::<f32>",
+        buf.len() as f64 / best / 1e6,
+        best * 1e9 / N as f64
+    );
+}
+
+// Formats the same `N` values each round; `format` returns the output length.
+fn run_format(name: &str, mut format: impl FnMut(u64) -> usize) {
+    let mut best = f64::MAX;
+    let mut bytes = 0;
+    for _ in 0..ROUNDS {
+        time(&mut best, || {
+            let mut x: u64 = 0x2545f4914f6cdd1d;
+            bytes = 0;
+            for _ in 0..N {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                bytes += format(black_box(x));
+            }
+            bytes as f64
+        });
+    }
+
+    println!("{}", name);
+    println!(
+        "  {:14} {:8.1} MB/s {:6.1} ns/number",
+        "format",
+        bytes as f64 / best / 1e6,
+        best * 1e9 / N as f64
+    );
+}
+
+fn main() {
+    let mut b = float::to_bytes::f64::Buffer::new();
+    let (buf, numbers) = dataset(|x| b.format(f64::from_bits(x >> 2)).to_owned());
+    run("shortest f64", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| format!("{}.{:03}", x >> 54, x % 1000));
+    run("fixed 3.3", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| format!("{}.{:06}", x >> 44, x % 1000000));
+    run("fixed 7.6", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| format!("{}.{:010}", x >> 34, x % 10000000000));
+    run("fixed 9.10", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| format!("{:.40e}", f64::from_bits(x >> 2)));
+    run("41 significant digits", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| format!("{:.760e}", f64::from_bits(x >> 12)));
+    run("761 significant digits, subnormal", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| halfway(f64::from_bits(0x3ff0000000000000 - (x >> 26))));
+    run("halfway points in [0.5, 1)", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| halfway(f64::from_bits(x >> 12)));
+    run("halfway points, subnormal", &buf, &numbers);
+
+    let mut b = float::to_bytes::f32::Buffer::new();
+    let (buf, numbers) = dataset(|x| b.format((x >> 40) as f32 / 16777216.0 * 1000.0).to_owned());
+    run_f32("shortest f32 in [0, 1000)", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| format!("{}.{:04}", x >> 50, x % 10000));
+    run_f32("fixed 5.4 as f32", &buf, &numbers);
+
+    let (buf, numbers) = dataset(|x| format!("{}e-{}", x >> 40, x % 12));
+    run_f32("8 digits, e-0..e-11 as f32", &buf, &numbers);
+
+    let mut b = float::to_bytes::f64::Buffer::new();
+    run_format("shortest f64", |x| b.format(f64::from_bits(x >> 2)).len());
+    run_format("engineering f64", |x| b.format_eng(f64::from_bits(x >> 2)).len());
+
+    let mut b = float::to_bytes::f32::Buffer::new();
+    run_format("shortest f32", |x| b.format(f32::from_bits((x >> 34) as u32)).len());
+
+    let mut b = float::to_bytes::int::Buffer::new();
+    run_format("u64", |x| b.format(x >> (x % 64)).len());
+}
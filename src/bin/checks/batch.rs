@@ -0,0 +1,164 @@
+use float::from_bytes::{parse_csv_columns, parse_delimited, BatchError, Error};
+
+use crate::Check;
+
+// Fields around the eight-byte steps of the digit skipping, numbers the
+// tokenizer reads several ways, and fields that are not numbers.
+const FIELDS: [&str; 20] = [
+    "1",
+    "-2.5",
+    "3e2",
+    ".5",
+    "1.",
+    "0.1",
+    "1e400",
+    "1234567",
+    "12345678",
+    "123456789",
+    "1234567812345678",
+    "12345678.12345678",
+    "12345678901234567890",
+    "",
+    "-",
+    "x",
+    "1x",
+    "1e",
+    "1 2",
+    "0x10",
+];
+
+const BLANKS: [&str; 4] = ["", " ", "\t", "  \r"];
+
+const COLUMNS: [&[usize]; 6] = [&[0], &[1, 0], &[2, 2], &[0, 1, 2], &[3], &[]];
+
+fn is_blank(c: u8) -> bool {
+    c == b' ' || c == b'\t' || c == b'\r'
+}
+
+// Splits on the delimiter, or on runs of blanks when it is one, and trims
+// blanks off each field. Fields come with their offsets.
+fn fields(line: &[u8], offset: usize, delimiter: u8) -> Vec<(usize, &[u8])> {
+    fn trim(start: usize, field: &[u8]) -> (usize, &[u8]) {
+        let lead = field.iter().take_while(|&&c| is_blank(c)).count();
+        let rest = &field[lead..];
+        let trail = rest.iter().rev().take_while(|&&c| is_blank(c)).count();
+        (start + lead, &rest[..rest.len() - trail])
+    }
+    if is_blank(delimiter) {
+        let mut fields = Vec::new();
+        let mut start = 0;
+        for part in line.split(|&c| is_blank(c)) {
+            if !part.is_empty() {
+                fields.push(trim(offset + start, part));
+            }
+            start += part.len() + 1;
+        }
+        fields
+    } else if line.iter().all(|&c| is_blank(c)) {
+        Vec::new()
+    } else {
+        let mut fields = Vec::new();
+        let mut start = 0;
+        for part in line.split(|&c| c == delimiter) {
+            fields.push(trim(offset + start, part));
+            start += part.len() + 1;
+        }
+        fields
+    }
+}
+
+// The input read a line and a field at a time, with the standard library
+// for the numbers.
+fn reference(input: &[u8], delimiter: u8, columns: Option<&[usize]>) -> Result<Vec<u64>, BatchError> {
+    let mut out = Vec::new();
+    let mut offset = 0;
+    for (index, line) in input.split(|&c| c == b'\n').enumerate() {
+        let row = index + 1;
+        let fields = fields(line, offset, delimiter);
+        let mut selected = vec![None; columns.map_or(0, <[usize]>::len)];
+        for (column, &(start, field)) in fields.iter().enumerate() {
+            let error = |kind| BatchError {
+                row,
+                column,
+                offset: start,
+                kind,
+            };
+            if field.is_empty() {
+                return Err(error(Error::Empty));
+            }
+            if columns.is_none_or(|c| c.contains(&column)) {
+                let value = std::str::from_utf8(field).unwrap().parse::<f64>().map_err(|_| error(Error::Invalid))?;
+                match columns {
+                    Some(columns) => {
+                        for (slot, &c) in selected.iter_mut().zip(columns) {
+                            if c == column {
+                                *slot = Some(value.to_bits());
+                            }
+                        }
+                    }
+                    None => out.push(value.to_bits()),
+                }
+            }
+        }
+        if let Some(columns) = columns {
+            if !fields.is_empty() {
+                if let Some(&missing) = columns.iter().filter(|&&c| c >= fields.len()).min() {
+                    return Err(BatchError {
+                        row,
+                        column: missing,
+                        offset: offset + line.len(),
+                        kind: Error::Empty,
+                    });
+                }
+                out.extend(selected.into_iter().flatten());
+            }
+        }
+        offset += line.len() + 1;
+    }
+    Ok(out)
+}
+
+// Documents of up to four rows of up to four fields, built from a fixed
+// pseudo-random sequence, for each delimiter and column selection.
+pub fn check(check: &mut Check) {
+    let mut state = 0x2545f4914f6cdd1du64;
+    let mut next = |n: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % n as u64) as usize
+    };
+
+    for _ in 0..20000 {
+        for delimiter in [b',', b';', b' ', b'\t'] {
+            let mut doc = String::new();
+            for row in 0..next(5) {
+                if row != 0 {
+                    doc.push('\n');
+                }
+                for field in 0..next(5) {
+                    if field != 0 {
+                        doc.push(delimiter as char);
+                    }
+                    doc.push_str(BLANKS[next(BLANKS.len())]);
+                    doc.push_str(FIELDS[next(FIELDS.len())]);
+                    doc.push_str(BLANKS[next(BLANKS.len())]);
+                }
+            }
+            if next(2) == 0 {
+                doc.push('\n');
+            }
+            let input = doc.as_bytes();
+
+            let mut out = Vec::new();
+            let got = parse_delimited::<f64>(input, delimiter, &mut out).map(|()| out.iter().map(|x| x.to_bits()).collect());
+            check.expect(&format!("parse_delimited({:?}, {:?})", doc, delimiter as char), got, reference(input, delimiter, None));
+            for columns in COLUMNS {
+                let mut out = Vec::new();
+                let got = parse_csv_columns::<f64>(input, delimiter, columns, &mut out).map(|()| out.iter().map(|x| x.to_bits()).collect());
+                let what = format!("parse_csv_columns({:?}, {:?}, {:?})", doc, delimiter as char, columns);
+                check.expect(&what, got, reference(input, delimiter, Some(columns)));
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use float::from_bytes::{parse_complex, Error};
+use float::to_bytes::complex::{Buffer, Style};
+
+use crate::Check;
+
+const F64S: [f64; 14] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.5,
+    0.1,
+    1e21,
+    -1e-7,
+    f64::MIN_POSITIVE,
+    -5e-324,
+    f64::MAX,
+    f64::MIN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+];
+
+// Equal, or both NaN: the formatter writes every NaN the same way.
+fn same(a: (u64, u64), b: (u64, u64), nan: impl Fn(u64) -> bool) -> bool {
+    let part = |x: u64, y: u64| x == y || (nan(x) && nan(y));
+    part(a.0, b.0) && part(a.1, b.1)
+}
+
+type Parsed = Result<(f64, f64), Error>;
+
+// Hand-written forms in each style, and near misses of them.
+const TEXT: [(&str, Parsed); 16] = [
+    ("1+2i", Ok((1.0, 2.0))),
+    ("1-2j", Ok((1.0, -2.0))),
+    (" -1.5e3+0.25I ", Ok((-1500.0, 0.25))),
+    ("3", Ok((3.0, 0.0))),
+    ("-2i", Ok((0.0, -2.0))),
+    ("-i", Ok((0.0, -1.0))),
+    ("4+j", Ok((4.0, 1.0))),
+    ("(1, -2)", Ok((1.0, -2.0))),
+    ("(1-2i)", Ok((1.0, -2.0))),
+    ("-inf+infi", Ok((f64::NEG_INFINITY, f64::INFINITY))),
+    ("", Err(Error::Empty)),
+    ("1+2", Err(Error::Invalid)),
+    ("1++2i", Err(Error::Invalid)),
+    ("1+-2i", Err(Error::Invalid)),
+    ("(1,2", Err(Error::Invalid)),
+    ("infinity", Err(Error::Invalid)),
+];
+
+pub fn check(check: &mut Check) {
+    let mut buf = Buffer::new();
+    let mut values: Vec<f64> = F64S.to_vec();
+    values.push(-f64::NAN);
+    for style in [Style::I, Style::J, Style::Tuple] {
+        for &re in &values {
+            for &im in &values {
+                let s = buf.format(re, im, style).to_owned();
+                let got = parse_complex::<f64>(s.as_bytes()).map(|(a, b)| (a.to_bits(), b.to_bits()));
+                let ok = got.is_ok_and(|got| same(got, (re.to_bits(), im.to_bits()), |x| f64::from_bits(x).is_nan()));
+                check.expect(&format!("parse_complex({:?}) is ({:?}, {:?})", s, re, im), ok, true);
+
+                let (re, im) = (re as f32, im as f32);
+                let s = buf.format(re, im, style).to_owned();
+                let got = parse_complex::<f32>(s.as_bytes()).map(|(a, b)| (a.to_bits() as u64, b.to_bits() as u64));
+                let want = (re.to_bits() as u64, im.to_bits() as u64);
+                let ok = got.is_ok_and(|got| same(got, want, |x| f32::from_bits(x as u32).is_nan()));
+                check.expect(&format!("parse_complex({:?}) is ({:?}, {:?})", s, re, im), ok, true);
+            }
+        }
+    }
+
+    for (s, want) in TEXT {
+        check.expect(&format!("parse_complex({:?})", s), parse_complex::<f64>(s.as_bytes()), want);
+    }
+}
@@ -0,0 +1,92 @@
+use float::from_bytes::{parse, parse_si};
+
+use crate::Check;
+
+const SI_PREFIXES: &str = "yzafpnum kMGTPEZY";
+
+// What `format_eng` and `format_si` should write for a value that `{:e}`
+// writes as `sci`: the same shortest digits, with the point moved so the
+// exponent is a multiple of 3, and within yocto to yotta a prefix in its
+// place.
+fn expected(sci: &str, si: bool) -> String {
+    let (sign, sci) = match sci.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", sci),
+    };
+    let (mantissa, exp) = sci.split_once('e').unwrap();
+    let digits = mantissa.replace('.', "");
+    if digits == "0" {
+        return format!("{}{}", sign, if si { "0" } else { "0E0" });
+    }
+    let exp: i32 = exp.parse().unwrap();
+    let eng = exp.div_euclid(3) * 3;
+    let int_len = (exp - eng + 1) as usize;
+    let number = if digits.len() <= int_len {
+        format!("{:0<width$}", digits, width = int_len)
+    } else {
+        format!("{}.{}", &digits[..int_len], &digits[int_len..])
+    };
+    let suffix = if si && (-24..=24).contains(&eng) {
+        let i = (eng / 3 + 8) as usize;
+        SI_PREFIXES[i..=i].trim().to_string()
+    } else {
+        format!("E{}", eng)
+    };
+    format!("{}{}{}", sign, number, suffix)
+}
+
+// Numbers at each power of ten, and just under the next one, where the
+// shortest digits round up into a new exponent. The caller adds the
+// neighbours of each.
+fn values(min: i32, max: i32) -> Vec<String> {
+    let mut values = Vec::new();
+    for k in min..=max {
+        for mantissa in ["1", "9.99", "99.9", "999.5", "999.9999999999999", "4.7"] {
+            values.push(format!("{}e{}", mantissa, k));
+        }
+    }
+    values
+}
+
+// Each value and its negation is written exactly as `expected` says and
+// parses back to itself; non-finite values are written as by `format`.
+pub fn check(check: &mut Check) {
+    let mut buf = float::to_bytes::f64::Buffer::new();
+    let mut list: Vec<f64> = vec![0.0, 5e-324, f64::MIN_POSITIVE, f64::MAX];
+    for s in values(-325, 309) {
+        let x: f64 = s.parse().unwrap();
+        let bits = x.to_bits();
+        list.extend([bits.saturating_sub(1), bits, bits + 1].map(f64::from_bits));
+    }
+    for x in list.iter().flat_map(|&x| [x, -x]).filter(|x| x.is_finite()) {
+        let sci = format!("{:e}", x);
+        let eng = buf.format_eng(x).to_owned();
+        check.expect(&format!("format_eng({:e})", x), eng.clone(), expected(&sci, false));
+        check.expect(&format!("parse({:?})", eng), parse(eng.as_bytes()).map(f64::to_bits), Ok(x.to_bits()));
+        let si = buf.format_si(x).to_owned();
+        check.expect(&format!("format_si({:e})", x), si.clone(), expected(&sci, true));
+        check.expect(&format!("parse_si({:?})", si), parse_si(si.as_bytes()).map(f64::to_bits), Ok(x.to_bits()));
+    }
+    for x in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+        let want = buf.format(x).to_owned();
+        check.expect(&format!("format_eng({})", x), buf.format_eng(x).to_owned(), want.clone());
+        check.expect(&format!("format_si({})", x), buf.format_si(x).to_owned(), want);
+    }
+
+    let mut buf = float::to_bytes::f32::Buffer::new();
+    let mut list: Vec<f32> = vec![0.0, 1e-45, f32::MIN_POSITIVE, f32::MAX];
+    for s in values(-46, 39) {
+        let x: f32 = s.parse().unwrap();
+        let bits = x.to_bits();
+        list.extend([bits.saturating_sub(1), bits, bits + 1].map(f32::from_bits));
+    }
+    for x in list.iter().flat_map(|&x| [x, -x]).filter(|x| x.is_finite()) {
+        let sci = format!("{:e}", x);
+        let eng = buf.format_eng(x).to_owned();
+        check.expect(&format!("format_eng({:e}f32)", x), eng.clone(), expected(&sci, false));
+        check.expect(&format!("parse({:?})", eng), parse(eng.as_bytes()).map(f32::to_bits), Ok(x.to_bits()));
+        let si = buf.format_si(x).to_owned();
+        check.expect(&format!("format_si({:e}f32)", x), si.clone(), expected(&sci, true));
+        check.expect(&format!("parse_si({:?})", si), parse_si(si.as_bytes()).map(f32::to_bits), Ok(x.to_bits()));
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt::Debug;
+use std::num::IntErrorKind;
+use std::str::FromStr;
+
+use float::from_bytes::{parse_int, parse_int_partial, Error};
+use float::to_bytes::int::Buffer;
+
+use crate::Check;
+
+// Magnitudes at every power of two and ten and one either side, which puts
+// each type's MIN and MAX and one past them among the inputs, along with
+// the digit counts where the parser leaves its unchecked loops.
+fn magnitudes() -> Vec<String> {
+    let mut values = vec![u128::MAX];
+    for k in 0..128 {
+        let p = 1u128 << k;
+        values.extend([p - 1, p, p + 1]);
+    }
+    let mut p = 1u128;
+    for _ in 0..=38 {
+        values.extend([p - 1, p, p.saturating_add(1)]);
+        p = p.saturating_mul(10);
+    }
+    values.sort_unstable();
+    values.dedup();
+    let mut magnitudes: Vec<String> = values.iter().map(u128::to_string).collect();
+    magnitudes.extend([
+        "340282366920938463463374607431768211456".to_string(),
+        format!("1{}", "0".repeat(39)),
+        "9".repeat(40),
+        "9".repeat(60),
+    ]);
+    magnitudes
+}
+
+fn std_error(kind: &IntErrorKind) -> Error {
+    match kind {
+        IntErrorKind::Empty => Error::Empty,
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Error::Overflow,
+        _ => Error::Invalid,
+    }
+}
+
+fn ints<I>(check: &mut Check, buffer: &mut Buffer, name: &str, inputs: &[String])
+where
+    I: float::from_bytes::Integer + float::to_bytes::int::Integer + FromStr<Err = std::num::ParseIntError>,
+    I: Debug + PartialEq + ToString,
+{
+    for s in inputs {
+        let want = s.parse::<I>().map_err(|e| std_error(e.kind()));
+        check.expect(&format!("parse_int::<{}>({:?})", name, s), parse_int::<I>(s.as_bytes()), want);
+
+        // The same number with more text after it, except for the empty
+        // input, which is no longer empty.
+        if !s.is_empty() {
+            let partial = format!("{}x1", s);
+            let want_partial = want.map(|value| (value, s.len()));
+            let got = parse_int_partial::<I>(partial.as_bytes());
+            check.expect(&format!("parse_int_partial::<{}>({:?})", name, partial), got, want_partial);
+        }
+
+        if let Ok(value) = want {
+            let formatted = buffer.format(value).to_string();
+            check.expect(&format!("{} formatted as {}", s, name), formatted, value.to_string());
+        }
+    }
+}
+
+// Every integer type against `str::parse` and `to_string`, with each sign
+// and with leading zeros, so that parsing and formatting both meet every
+// boundary from i8::MIN to u128::MAX.
+pub fn check(check: &mut Check) {
+    let mut inputs = vec![String::new(), "+".to_string(), "-".to_string(), "+-1".to_string(), "--1".to_string()];
+    for m in magnitudes() {
+        for prefix in ["", "+", "-", "0", "-0", "+000", &"0".repeat(30), &format!("-{}", "0".repeat(30))] {
+            inputs.push(format!("{}{}", prefix, m));
+        }
+    }
+
+    let mut buffer = Buffer::new();
+    ints::<i8>(check, &mut buffer, "i8", &inputs);
+    ints::<i16>(check, &mut buffer, "i16", &inputs);
+    ints::<i32>(check, &mut buffer, "i32", &inputs);
+    ints::<i64>(check, &mut buffer, "i64", &inputs);
+    ints::<i128>(check, &mut buffer, "i128", &inputs);
+    ints::<isize>(check, &mut buffer, "isize", &inputs);
+    ints::<u8>(check, &mut buffer, "u8", &inputs);
+    ints::<u16>(check, &mut buffer, "u16", &inputs);
+    ints::<u32>(check, &mut buffer, "u32", &inputs);
+    ints::<u64>(check, &mut buffer, "u64", &inputs);
+    ints::<u128>(check, &mut buffer, "u128", &inputs);
+    ints::<usize>(check, &mut buffer, "usize", &inputs);
+}
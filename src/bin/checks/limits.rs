@@ -0,0 +1,46 @@
+use float::from_bytes::{parse_with_limits, Error, Limits};
+
+use crate::Check;
+
+fn limited(check: &mut Check, limits: &Limits, s: &str, want: Result<f64, Error>) {
+    let got = parse_with_limits::<f64>(s.as_bytes(), limits);
+    check.expect(&format!("parse_with_limits({:?}, {:?})", s, limits), got, want);
+}
+
+// Every cap exactly at and one past its limit, and exponents far enough
+// past the tokenizer's own saturation point to tell any two caps apart.
+pub fn check(check: &mut Check) {
+    let limits = Limits::default();
+    let value = |s: &str| Ok(s.parse::<f64>().unwrap());
+    for s in ["1e400", "1e-400", "12e399", "0.001e403", "-9.9e400", "0e99999999999999999999"] {
+        limited(check, &limits, s, value(s));
+    }
+    for s in ["1e401", "1e-401", "123e399", "0.0001e-398", "1e268435456", "1e-2684354550", "1e99999999999999999999"] {
+        limited(check, &limits, s, Err(Error::ExponentTooLarge));
+    }
+
+    let len = format!("{}1", "0".repeat(limits.max_len - 1));
+    limited(check, &limits, &len, value(&len));
+    limited(check, &limits, &format!("0{}", len), Err(Error::TooLong));
+    let digits = format!("0.000{}", "1".repeat(limits.max_digits));
+    limited(check, &limits, &digits, value(&digits));
+    limited(check, &limits, &format!("{}1", digits), Err(Error::TooManyDigits));
+    // Zeros at either end of the digits are not significant.
+    for s in [format!("1.{}", "0".repeat(900)), format!("1{}.{}", "0".repeat(300), "0".repeat(600)), format!("1{}e-600", "0".repeat(900))] {
+        limited(check, &limits, &s, value(&s));
+    }
+    let spread = format!("1{}1{}", "0".repeat(limits.max_digits - 1), "0".repeat(100));
+    limited(check, &limits, &spread, Err(Error::TooManyDigits));
+
+    // The widest cap, which no exponent the tokenizer keeps can reach.
+    let widest = Limits {
+        max_exponent: u32::MAX,
+        ..limits
+    };
+    for s in ["1e4294967295", "1e-4294967295", "0.1e-4294967294", "9e4294967295", "1e268435457"] {
+        limited(check, &widest, s, value(s));
+    }
+    for s in ["1e4294967296", "10e4294967295", "1e-4294967296", "1e18446744073709551616"] {
+        limited(check, &widest, s, Err(Error::ExponentTooLarge));
+    }
+}
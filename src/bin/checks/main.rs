@@ -0,0 +1,59 @@
+extern crate float;
+
+use std::fmt::Debug;
+use std::process::exit;
+
+mod batch;
+mod complex;
+mod eng;
+mod int;
+mod limits;
+mod matrix;
+mod scan;
+mod si;
+mod simd;
+mod stream;
+mod uncertainty;
+
+// Boundary checks for the APIs built around the parser, each against what
+// the standard library, or a direct reading of the input, says the answer
+// should be:
+//
+//     cargo run --release --features __checks --bin checks
+
+pub struct Check {
+    inputs: u64,
+    fails: u64,
+}
+
+impl Check {
+    pub fn expect<T: Debug + PartialEq>(&mut self, what: &str, got: T, want: T) {
+        self.inputs += 1;
+        if got != want {
+            self.fails += 1;
+            if self.fails <= 20 {
+                println!("{}: got {:?}, want {:?}", what, got, want);
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut check = Check { inputs: 0, fails: 0 };
+    batch::check(&mut check);
+    complex::check(&mut check);
+    eng::check(&mut check);
+    int::check(&mut check);
+    limits::check(&mut check);
+    matrix::check(&mut check);
+    scan::check(&mut check);
+    si::check(&mut check);
+    simd::check(&mut check);
+    stream::check(&mut check);
+    uncertainty::check(&mut check);
+
+    println!("{} inputs, {} failures", check.inputs, check.fails);
+    if check.fails != 0 {
+        exit(1);
+    }
+}
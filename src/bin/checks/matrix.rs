@@ -0,0 +1,121 @@
+use float::from_bytes::{read_matrix, MatrixError, MatrixErrorKind, MatrixOptions, Missing};
+
+use crate::Check;
+
+// Numbers, the missing-value tokens, fields that are not numbers, and a
+// comment that starts inside a field.
+const FIELDS: [&str; 12] = ["1", "-2.5", ".5", "1e400", "12345678901234567890", "NA", "", "-", "x", "1#2", "# c", "0.1"];
+
+const BLANKS: [&str; 4] = ["", " ", "\t", "\r"];
+
+const MISSING: [&[&[u8]]; 3] = [&[b"NA", b""], &[], &[b"-"]];
+
+type Read = Result<(Vec<u64>, usize, usize), MatrixError>;
+
+// The input read a line and a field at a time, with the standard library
+// for the numbers.
+fn reference(input: &[u8], options: &MatrixOptions<'_>) -> Read {
+    let (mut data, mut rows, mut cols) = (Vec::new(), 0, 0);
+    for (index, line) in input.split(|&c| c == b'\n').enumerate().skip(options.skip_header) {
+        let line = match options.comment.and_then(|c| line.iter().position(|&x| x == c)) {
+            Some(end) => &line[..end],
+            None => line,
+        };
+        let line = line.trim_ascii();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&[u8]> = match options.delimiter {
+            Some(delimiter) => line.split(|&c| c == delimiter).map(<[u8]>::trim_ascii).collect(),
+            None => line.split(u8::is_ascii_whitespace).filter(|f| !f.is_empty()).collect(),
+        };
+        for (column, &field) in fields.iter().enumerate() {
+            let error = |kind| MatrixError {
+                line: index + 1,
+                column,
+                kind,
+            };
+            let value = if options.missing.contains(&field) {
+                match options.on_missing {
+                    Missing::Nan => f64::NAN,
+                    Missing::Error => return Err(error(MatrixErrorKind::Missing)),
+                }
+            } else {
+                let s = std::str::from_utf8(field).unwrap();
+                s.parse::<f64>().map_err(|_| error(MatrixErrorKind::Invalid))?
+            };
+            data.push(value.to_bits());
+        }
+        if rows == 0 {
+            cols = fields.len();
+        } else if fields.len() != cols {
+            return Err(MatrixError {
+                line: index + 1,
+                column: fields.len().min(cols),
+                kind: MatrixErrorKind::Columns {
+                    expected: cols,
+                    found: fields.len(),
+                },
+            });
+        }
+        rows += 1;
+    }
+    Ok((data, rows, cols))
+}
+
+// Documents of up to five lines, mostly of the same width, built from a
+// fixed pseudo-random sequence and read under each combination of options.
+pub fn check(check: &mut Check) {
+    let mut state = 0x9e3779b97f4a7c15u64;
+    let mut next = |n: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % n as u64) as usize
+    };
+
+    for _ in 0..5000 {
+        for delimiter in [None, Some(b',')] {
+            let separator = delimiter.map_or(' ', char::from);
+            let width = 1 + next(3);
+            let mut doc = String::new();
+            for line in 0..next(6) {
+                if line != 0 {
+                    doc.push('\n');
+                }
+                let fields = if next(4) == 0 { next(4) } else { width };
+                for field in 0..fields {
+                    if field != 0 {
+                        doc.push(separator);
+                    }
+                    doc.push_str(BLANKS[next(BLANKS.len())]);
+                    doc.push_str(FIELDS[next(FIELDS.len())]);
+                    doc.push_str(BLANKS[next(BLANKS.len())]);
+                }
+            }
+            if next(2) == 0 {
+                doc.push('\n');
+            }
+
+            for missing in MISSING {
+                for on_missing in [Missing::Nan, Missing::Error] {
+                    for comment in [Some(b'#'), None] {
+                        for skip_header in [0, 1] {
+                            let options = MatrixOptions {
+                                delimiter,
+                                comment,
+                                skip_header,
+                                missing,
+                                on_missing,
+                            };
+                            let got = read_matrix(doc.as_bytes(), &options)
+                                .map(|m| (m.data.iter().map(|x| x.to_bits()).collect(), m.rows, m.cols));
+                            let want = reference(doc.as_bytes(), &options);
+                            check.expect(&format!("read_matrix({:?}, {:?})", doc, options), got, want);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
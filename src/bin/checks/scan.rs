@@ -0,0 +1,123 @@
+use std::ops::Range;
+
+use float::from_bytes::{scan_with, Boundary};
+
+use crate::Check;
+
+const LITERALS: [&str; 15] = [
+    "0",
+    "7",
+    "12.5",
+    "0.75",
+    ".5",
+    "1.",
+    "3.1e-4",
+    "1E+10",
+    "0.000001",
+    "123456789012345678901234567890",
+    "2.2250738585072011e-308",
+    "4.9e-324",
+    "1e-400",
+    "1e308",
+    "1.8e308",
+];
+
+// What comes before a literal: nothing, separators, word characters, a
+// minus sign with and without a word before it, and a byte with the high
+// bit set. A dot would run into the literal, which ".5" covers.
+const BEFORE: [&str; 10] = ["", " ", "=", "(", "x", "_", "-", "a-", "--", "\u{e9}"];
+
+// What comes after it: separators, units, dots that do and do not make a
+// version string, and exponent markers with nothing to finish them.
+const AFTER: [&str; 14] = ["", " ", ",", ")", "ms", "_", ".", ".x", ".5", "..", "e", "E+", "e-x", "\u{e9}"];
+
+const BOUNDARIES: [Boundary; 3] = [Boundary::Any, Boundary::Word, Boundary::Strict];
+
+fn is_word(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+// The one number a scan of `before`, `literal`, `after` should find, read
+// off the rules for each boundary: a word before the literal, or a hyphen
+// after a word, hides it unless any boundary goes; a minus sign not attached
+// to a word is part of it; a dot and a digit after it make a version string;
+// an underscore after it hides it unless any boundary goes, and a word after
+// it under the strict boundary; and a trailing dot is left out of the range.
+fn expected(before: &str, literal: &str, after: &str, boundary: Boundary) -> Vec<(Range<usize>, u64)> {
+    let (b, a) = (before.as_bytes(), after.as_bytes());
+    let mut start = b.len();
+    let mut end = start + literal.len();
+    let mut text = literal.to_string();
+    if let Some(&prev) = b.last() {
+        if boundary != Boundary::Any && is_word(prev) {
+            return Vec::new();
+        }
+        if prev == b'-' && b.len() >= 2 && is_word(b[b.len() - 2]) {
+            if boundary != Boundary::Any {
+                return Vec::new();
+            }
+        } else if prev == b'-' {
+            start -= 1;
+            text.insert(0, '-');
+        }
+    }
+    if a.first() == Some(&b'.') && a.get(1).is_some_and(|&c| c.is_ascii_digit() || c == b'.') {
+        return Vec::new();
+    }
+    if boundary == Boundary::Strict && a.first().is_some_and(|&c| is_word(c)) {
+        return Vec::new();
+    }
+    if boundary != Boundary::Any && a.first() == Some(&b'_') {
+        return Vec::new();
+    }
+    if literal.ends_with('.') {
+        end -= 1;
+    }
+    vec![(start..end, text.parse::<f64>().unwrap().to_bits())]
+}
+
+type Found = &'static [(Range<usize>, f64)];
+
+// Sentences with several numbers, and version strings, under the default
+// boundary.
+const TEXT: [(&str, Found); 14] = [
+    ("took 12.5ms, error=3.1e-4, load 0.75", &[(5..9, 12.5), (19..25, 3.1e-4), (32..36, 0.75)]),
+    ("1.2.3 and 4", &[(10..11, 4.0)]),
+    ("v1.2 x2 -3", &[(8..10, -3.0)]),
+    ("2-3", &[]),
+    ("2020-10-19, pages 3-4 of x-5 (-5)", &[(30..32, -5.0)]),
+    ("0x1F 1_000 1e3_ 12ms2 7ms", &[(22..23, 7.0)]),
+    ("12.5ms 3kg, 1e-3s", &[(0..4, 12.5), (7..8, 3.0), (12..16, 1e-3)]),
+    ("x86 w64 -1.5e", &[(8..12, -1.5)]),
+    ("5-x 6- 7-", &[(0..1, 5.0), (4..5, 6.0), (7..8, 7.0)]),
+    ("4 -3_", &[(0..1, 4.0)]),
+    ("1e5e5 .5. 5..", &[(6..8, 0.5), (10..11, 5.0)]),
+    ("a.5 (.5) -.5", &[(5..7, 0.5), (9..12, -0.5)]),
+    ("1.5.x x1e-4", &[(0..3, 1.5)]),
+    ("", &[]),
+];
+
+// Every literal between every pair of contexts under each boundary, against
+// `expected`.
+pub fn check(check: &mut Check) {
+    for literal in LITERALS {
+        for before in BEFORE {
+            for after in AFTER {
+                // A dot after an integer would run into it, which "1." covers.
+                if after.starts_with('.') && !literal.contains(['.', 'e', 'E']) {
+                    continue;
+                }
+                let s = format!("{}{}{}", before, literal, after);
+                for boundary in BOUNDARIES {
+                    let got: Vec<_> = scan_with::<f64>(s.as_bytes(), boundary).map(|(r, x)| (r, x.to_bits())).collect();
+                    check.expect(&format!("scan_with({:?}, {:?})", s, boundary), got, expected(before, literal, after, boundary));
+                }
+            }
+        }
+    }
+
+    for (s, want) in TEXT {
+        let got: Vec<_> = scan_with::<f64>(s.as_bytes(), Boundary::Word).collect();
+        check.expect(&format!("scan({:?})", s), got, want.to_vec());
+    }
+}
@@ -0,0 +1,100 @@
+use float::from_bytes::{parse_si, Error};
+
+use crate::Check;
+
+const PREFIXES: [(&str, i64); 21] = [
+    ("", 0),
+    ("y", -24),
+    ("z", -21),
+    ("a", -18),
+    ("f", -15),
+    ("p", -12),
+    ("n", -9),
+    ("u", -6),
+    ("\u{b5}", -6),
+    ("\u{3bc}", -6),
+    ("m", -3),
+    ("k", 3),
+    ("M", 6),
+    ("G", 9),
+    ("T", 12),
+    ("P", 15),
+    ("E", 18),
+    ("Z", 21),
+    ("Y", 24),
+    ("e0E", 18),
+    ("e-3u", -9),
+];
+
+const MANTISSAS: [&str; 12] = [
+    "0",
+    "1",
+    "4.7",
+    "0.1",
+    ".5",
+    "220.",
+    "999.9999999999999999",
+    "1.7976931348623157",
+    "2.2250738585072011",
+    "4.9406564584124654",
+    "2.4703282292062328",
+    "9007199254740993",
+];
+
+// Written exponents that put the prefixes on either side of the limits of
+// both types, and past the point where the tokenizer saturates.
+const EXPONENTS: [i64; 16] = [0, 3, -3, 38, -45, 284, 285, 308, -300, -301, -324, -330, 400, -400, 268435455, -268435456];
+
+const INVALID: [(&str, Error); 12] = [
+    ("", Error::Empty),
+    ("k", Error::Invalid),
+    ("E", Error::Invalid),
+    ("1kk", Error::Invalid),
+    ("1 k", Error::Invalid),
+    ("1K", Error::Invalid),
+    ("1da", Error::Invalid),
+    ("1e", Error::Invalid),
+    ("1EE", Error::Invalid),
+    ("1\u{b5}m", Error::Invalid),
+    ("1u ", Error::Invalid),
+    ("+1k", Error::Invalid),
+];
+
+// Each prefix, including both micro signs and a prefix after a written
+// exponent, against the same number with the prefix folded into its
+// exponent before it is parsed once by the standard library.
+pub fn check(check: &mut Check) {
+    for mantissa in MANTISSAS {
+        for exp in EXPONENTS {
+            for (prefix, scale) in PREFIXES {
+                for sign in ["", "-"] {
+                    let written = match exp {
+                        0 => String::new(),
+                        _ => format!("e{}", exp),
+                    };
+                    let s = format!("{}{}{}{}", sign, mantissa, written, prefix);
+                    // "1e3e0E" is not a number.
+                    if !written.is_empty() && prefix.starts_with('e') {
+                        continue;
+                    }
+                    let folded = format!("{}{}e{}", sign, mantissa, exp + scale);
+                    // Compared by bits, so that the sign of zero counts.
+                    let want = folded.parse::<f64>().map(f64::to_bits).map_err(|_| Error::Invalid);
+                    let got = parse_si::<f64>(s.as_bytes()).map(f64::to_bits);
+                    check.expect(&format!("parse_si::<f64>({:?})", s), got, want);
+                    let want = folded.parse::<f32>().map(f32::to_bits).map_err(|_| Error::Invalid);
+                    let got = parse_si::<f32>(s.as_bytes()).map(f32::to_bits);
+                    check.expect(&format!("parse_si::<f32>({:?})", s), got, want);
+                }
+            }
+        }
+    }
+
+    for (s, want) in INVALID {
+        check.expect(&format!("parse_si::<f64>({:?})", s), parse_si::<f64>(s.as_bytes()), Err(want));
+    }
+    // Halves of the two-byte micro signs, and one too many.
+    for bytes in [&b"1\xb5"[..], b"1\xc2", b"1\xce", b"1\xc2\xb5\xb5", b"1\xb5\xc2"] {
+        check.expect(&format!("parse_si::<f64>({:?})", bytes), parse_si::<f64>(bytes), Err(Error::Invalid));
+    }
+}
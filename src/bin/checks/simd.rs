@@ -0,0 +1,73 @@
+use float::from_bytes::layouts;
+
+use crate::Check;
+
+type Layout = (bool, usize, bool, usize, bool);
+
+// What the vector paths should find: the scalar classifier's layout.
+fn expect(check: &mut Check, d: &[u8]) {
+    let mut want = None;
+    let mut fails = Vec::new();
+    layouts(d, |name, got| match want {
+        None => want = Some(got),
+        Some(want) if got != want => fails.push((name, got)),
+        Some(_) => {}
+    });
+    check.expect(&format!("layouts of {:?}, scalar {:?}", d, want), fails, Vec::<(&str, Layout)>::new());
+}
+
+// Every vector classifier against the scalar one, read from slices at
+// several alignments. First single runs ending at every position across
+// the 16- and 32-byte blocks, on each non-digit in turn, which includes the
+// neighbours of '0' and '9', '.', the exponent markers and bytes with the
+// high bit set. Then numbers with a sign, a fraction and an exponent
+// marker, with the '.' and the byte after the fraction at every position.
+pub fn check(check: &mut Check) {
+    let mut names = Vec::new();
+    layouts(b"", |name, _| names.push(name));
+    println!("classifiers: {}", names.join(", "));
+
+    let mut buf = [0u8; 8 + 72 + 8];
+    for stop in (0..=255u8).filter(|c| !c.is_ascii_digit()) {
+        for align in [0, 1, 7, 8] {
+            for len in 0..=72 {
+                for run in 0..=len {
+                    let d = &mut buf[align..align + len];
+                    for (i, c) in d.iter_mut().enumerate() {
+                        *c = b"0123456789"[(i * 7 + run) % 10];
+                    }
+                    if run < len {
+                        d[run] = stop;
+                    }
+                    expect(check, d);
+                }
+            }
+        }
+    }
+
+    let mut buf = [0u8; 8 + 96];
+    for neg in [false, true] {
+        for align in [0, 1] {
+            for integer in 0..=36 {
+                for fraction in 0..=36 {
+                    for stop in [b'e', b'E', b'.', b'-', b' ', b'f', 0x80] {
+                        for tail in [0, 1, 20] {
+                            let mut n = Vec::new();
+                            if neg {
+                                n.push(b'-');
+                            }
+                            n.extend((0..integer).map(|i| b'0' + (i % 10) as u8));
+                            n.push(b'.');
+                            n.extend((0..fraction).map(|i| b'9' - (i % 10) as u8));
+                            n.push(stop);
+                            n.extend((0..tail).map(|i| b'0' + (i % 10) as u8));
+                            let d = &mut buf[align..align + n.len()];
+                            d.copy_from_slice(&n);
+                            expect(check, d);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::io::{self, Read};
+
+use float::from_bytes::{Error, Reader, Stream, StreamError};
+
+use crate::Check;
+
+// Numbers and bad tokens between assorted delimiters. Each good token is
+// checked against the standard library, and each bad one has to come back
+// as one error at its first byte, with the tokens after it unaffected.
+const TOKENS: [&str; 18] = [
+    "0",
+    "-0.0",
+    "1.5e-7",
+    ".5",
+    "7.",
+    "12x34",
+    "1e",
+    "-",
+    "9007199254740993",
+    "2.4703282292062328e-324",
+    "1e+",
+    "--1",
+    "1e400",
+    "1.2.3",
+    "e5",
+    "1E-2684354550",
+    "123456789012345678901234567890",
+    "4.9406564584124654e-324x",
+];
+
+const DELIMITERS: [&str; 5] = [" ", "\n", ",", ";", " \t\r\n"];
+
+fn expected(input: &str, tokens: &[String]) -> Vec<Result<u64, StreamError>> {
+    let mut want = Vec::new();
+    let mut at = 0;
+    for token in tokens {
+        let offset = at + input[at..].find(token.as_str()).unwrap();
+        at = offset + token.len();
+        want.push(match token.parse::<f64>() {
+            Ok(f) => Ok(f.to_bits()),
+            Err(_) => Err(StreamError {
+                offset,
+                kind: Error::Invalid,
+            }),
+        });
+    }
+    want
+}
+
+// Hands out its input a few bytes at a time, however much was asked for.
+struct Trickle<'a> {
+    input: &'a [u8],
+    step: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.step.min(buf.len()).min(self.input.len());
+        buf[..n].copy_from_slice(&self.input[..n]);
+        self.input = &self.input[n..];
+        Ok(n)
+    }
+}
+
+fn fed(input: &[u8], step: usize) -> Vec<Result<u64, StreamError>> {
+    let mut stream = Stream::<f64>::new();
+    let mut got = Vec::new();
+    for chunk in input.chunks(step) {
+        stream.feed(chunk, |r| got.push(r.map(f64::to_bits)));
+    }
+    if let Some(r) = stream.finish().transpose() {
+        got.push(r.map(f64::to_bits));
+    }
+    got
+}
+
+fn read(input: &[u8], step: usize) -> Vec<Result<u64, StreamError>> {
+    let reader = Reader::<_, f64>::new(Trickle { input, step });
+    reader
+        .map(|r| match r {
+            Ok(f) => Ok(f.to_bits()),
+            Err(err) => Err(*err.into_inner().unwrap().downcast::<StreamError>().unwrap()),
+        })
+        .collect()
+}
+
+pub fn check(check: &mut Check) {
+    let mut tokens: Vec<String> = TOKENS.iter().map(|s| s.to_string()).collect();
+    // Longer than the digits a stream keeps, so the chunk edges land in the
+    // dropped part too.
+    tokens.push(format!("{:.1100}", 5e-324f64).trim_end_matches('0').to_owned());
+    tokens.push(format!("1{}e-900", "0".repeat(900)));
+
+    for (i, delimiter) in DELIMITERS.iter().enumerate() {
+        let mut input = tokens.join(delimiter);
+        // With and without a delimiter after the last token.
+        if i % 2 == 0 {
+            input.push_str(delimiter);
+        }
+        let want = expected(&input, &tokens);
+        for step in (1..24).chain([64, 1000, input.len()]) {
+            check.expect(&format!("feed in {}-byte chunks", step), fed(input.as_bytes(), step), want.clone());
+            check.expect(&format!("read {} bytes at a time", step), read(input.as_bytes(), step), want.clone());
+        }
+        // Every place to split it in two.
+        for split in 0..=input.len() {
+            let (a, b) = input.as_bytes().split_at(split);
+            let mut stream = Stream::<f64>::new();
+            let mut got = Vec::new();
+            stream.feed(a, |r| got.push(r.map(f64::to_bits)));
+            stream.feed(b, |r| got.push(r.map(f64::to_bits)));
+            if let Some(r) = stream.finish().transpose() {
+                got.push(r.map(f64::to_bits));
+            }
+            check.expect(&format!("feed split at {}", split), got, want.clone());
+        }
+    }
+}
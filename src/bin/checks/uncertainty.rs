@@ -0,0 +1,82 @@
+use float::from_bytes::{parse_uncertainty, Error};
+
+use crate::Check;
+
+const VALUES: [&str; 12] = [
+    "0",
+    "1",
+    "1.",
+    "-0.5",
+    "1.23456",
+    "6.02214076",
+    "1.7976931348623157",
+    "2.2250738585072011",
+    "4.9406564584124654",
+    "9.999999999999999999999",
+    "12345678901234567890.123",
+    "0.0000000000000000000000000000001",
+];
+
+const UNCERTAINTIES: [&str; 7] = ["0", "00", "1", "5", "12", "0012", "99999999999999999999999"];
+
+const EXPONENTS: [&str; 13] = [
+    "",
+    "e0",
+    "e-3",
+    "E23",
+    "e+308",
+    "e-308",
+    "e-324",
+    "e300",
+    "e-300",
+    "e400",
+    "e-400",
+    "e268435455",
+    "e-999999999999",
+];
+
+const INVALID: [(&str, Error); 14] = [
+    ("", Error::Empty),
+    ("(1)", Error::Invalid),
+    ("1.2", Error::Invalid),
+    ("1.2e3(4)", Error::Invalid),
+    ("1.2()", Error::Invalid),
+    ("1.2(1", Error::Invalid),
+    ("1.2(1a)", Error::Invalid),
+    ("1.2(-1)", Error::Invalid),
+    ("1.2(+1)", Error::Invalid),
+    ("1.2(1.5)", Error::Invalid),
+    ("1.2 (1)", Error::Invalid),
+    ("1.2(1)e", Error::Invalid),
+    ("1.2(1)x", Error::Invalid),
+    ("1.2(1)(2)", Error::Invalid),
+];
+
+// Every value, uncertainty and exponent together, against the standard
+// library parsing the value with the exponent, and the uncertainty with the
+// exponent less the number of fraction digits it lines up with.
+pub fn check(check: &mut Check) {
+    for value in VALUES {
+        let fraction = value.split_once('.').map_or(0, |(_, f)| f.len() as i64);
+        for uncertainty in UNCERTAINTIES {
+            for exp in EXPONENTS {
+                let s = format!("{}({}){}", value, uncertainty, exp);
+                let e: i64 = exp.get(1..).map_or(0, |e| e.parse().unwrap());
+                let want_value = format!("{}{}", value, exp);
+                let want_uncertainty = format!("{}e{}", uncertainty, e - fraction);
+
+                let want = (want_value.parse::<f64>().unwrap(), want_uncertainty.parse::<f64>().unwrap());
+                let got = parse_uncertainty::<f64>(s.as_bytes()).map(|(v, u)| (v.to_bits(), u.to_bits()));
+                check.expect(&format!("parse_uncertainty::<f64>({:?})", s), got, Ok((want.0.to_bits(), want.1.to_bits())));
+
+                let want = (want_value.parse::<f32>().unwrap(), want_uncertainty.parse::<f32>().unwrap());
+                let got = parse_uncertainty::<f32>(s.as_bytes()).map(|(v, u)| (v.to_bits(), u.to_bits()));
+                check.expect(&format!("parse_uncertainty::<f32>({:?})", s), got, Ok((want.0.to_bits(), want.1.to_bits())));
+            }
+        }
+    }
+
+    for (s, want) in INVALID {
+        check.expect(&format!("parse_uncertainty::<f64>({:?})", s), parse_uncertainty::<f64>(s.as_bytes()), Err(want));
+    }
+}
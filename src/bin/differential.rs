@@ -0,0 +1,291 @@
+extern crate float;
+
+use std::process::exit;
+
+use float::from_bytes::{const_parse, const_parse_f32, parse, parse_exact, Error};
+
+// Checks `parse` against the standard library on inputs that reach the slow
+// path: exact halfway points between neighbouring floats, the same points
+// cut short or nudged up, and long expansions. `const_parse` and
+// `const_parse_f32` have to agree with `parse` on all of them, and on the
+// table below when evaluated at compile time. `parse_exact` has to accept
+// exactly the inputs whose value the result prints as in full, which
+// includes those expansions themselves. Run it once per limb width:
+//
+//     cargo run --release --bin differential
+//     cargo run --release --bin differential --features limb32
+
+const LITERALS: [&str; 24] = [
+    "0",
+    "-0",
+    "0.1",
+    "-1.5e-7",
+    "123456789012345678901234567890",
+    "9007199254740993",
+    "1.7976931348623157e308",
+    "1.7976931348623158e308",
+    "1e309",
+    "2.2250738585072011e-308",
+    "2.2250738585072014e-308",
+    "4.9406564584124654e-324",
+    "2.4703282292062327e-324",
+    "2.4703282292062328e-324",
+    "1e-400",
+    "3.4028235e38",
+    "3.4028236e38",
+    "1.4e-45",
+    "7.038531e-26",
+    "1.00000000000000011102230246251565404236316680908203125",
+    "1.000000059604644775390625",
+    "0.000000000000000000000000000000000000000000001401298464324817070923729583289916131280261941876515771757068283889791082685860601486638188362121582031250000000000000001",
+    "4503599627370496.5000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+    "123.456e-2",
+];
+
+const F64S: [f64; LITERALS.len()] = {
+    let mut out = [0.0; LITERALS.len()];
+    let mut i = 0;
+    while i < LITERALS.len() {
+        out[i] = float::const_parse(LITERALS[i]);
+        i += 1;
+    }
+    out
+};
+
+const F32S: [f32; LITERALS.len()] = {
+    let mut out = [0.0; LITERALS.len()];
+    let mut i = 0;
+    while i < LITERALS.len() {
+        out[i] = const_parse_f32(LITERALS[i]);
+        i += 1;
+    }
+    out
+};
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+// Exact decimal expansion of the midpoint of two finite positive values,
+// each printed exactly by `{:.1100}`.
+fn midpoint(lo: f64, hi: f64) -> String {
+    let lo = format!("{:.1100}", lo);
+    let hi = format!("{:.1100}", hi);
+    let width = lo.len().max(hi.len()) + 1;
+    let digits = |s: &str| -> Vec<u8> {
+        let s = format!("{:0>width$}", s, width = width);
+        s.bytes().filter(|&c| c != b'.').map(|c| c - b'0').collect()
+    };
+    let (lo, hi) = (digits(&lo), digits(&hi));
+    let point = width - 1101;
+
+    let mut sum = vec![0; lo.len()];
+    let mut carry = 0;
+    for i in (0..lo.len()).rev() {
+        let d = lo[i] + hi[i] + carry;
+        sum[i] = d % 10;
+        carry = d / 10;
+    }
+
+    let mut out = String::new();
+    let mut rem = 0;
+    for (i, &d) in sum.iter().enumerate() {
+        if i == point {
+            out.push('.');
+        }
+        let d = rem * 10 + d;
+        out.push((b'0' + d / 2) as char);
+        rem = d % 2;
+    }
+    if rem != 0 {
+        out.push('5');
+    }
+    let out = out.trim_start_matches('0').trim_end_matches('0');
+    let out = out.trim_end_matches('.');
+    if out.starts_with('.') {
+        format!("0{}", out)
+    } else {
+        out.to_owned()
+    }
+}
+
+// The exact decimal expansion of `f`.
+fn expansion(f: f64) -> String {
+    let s = format!("{:.1100}", f);
+    s.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
+
+// Significant digits and where the decimal point goes relative to them, so
+// that equal values written differently compare equal.
+fn decimal(s: &str) -> (String, i64) {
+    let s = s.trim_start_matches('-');
+    let (mant, exp) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], s[i + 1..].parse::<i64>().unwrap()),
+        None => (s, 0),
+    };
+    let (int, frac) = mant.split_once('.').unwrap_or((mant, ""));
+    let all = format!("{}{}", int, frac);
+    let lead = all.len() - all.trim_start_matches('0').len();
+    let digits = all.trim_matches('0');
+    if digits.is_empty() {
+        return (String::new(), 0);
+    }
+    (digits.to_owned(), int.len() as i64 - lead as i64 + exp)
+}
+
+// What `parse_exact` should make of `s`, given the correctly rounded value
+// and how that value prints in full.
+fn exact(s: &str, bits: u64, infinite: bool, full: &str) -> Result<u64, Error> {
+    if infinite {
+        Err(Error::Overflow)
+    } else if decimal(s) == decimal(full) {
+        Ok(bits)
+    } else {
+        Err(Error::Inexact)
+    }
+}
+
+// The same number written with fewer digits, which is never above it.
+fn truncate(s: &str, keep: usize) -> String {
+    let end = s.find('.').map_or(s.len(), |p| (p + 1 + keep).min(s.len()));
+    s[..end].trim_end_matches('.').to_owned()
+}
+
+// Just above the same number.
+fn nudge(s: &str, zeros: usize) -> String {
+    let mut s = s.to_owned();
+    if !s.contains('.') {
+        s.push('.');
+    }
+    s.extend(std::iter::repeat_n('0', zeros));
+    s.push('1');
+    s
+}
+
+struct Check {
+    inputs: u64,
+    fails: u64,
+}
+
+impl Check {
+    fn f64(&mut self, s: &str) {
+        self.inputs += 1;
+        let want: f64 = s.parse().unwrap();
+        match parse::<f64>(s.as_bytes()) {
+            Ok(got) if got.to_bits() == want.to_bits() => {}
+            got => self.fail(s, &format!("{:?}", got), &format!("{:?}", want)),
+        }
+        let got = const_parse(s);
+        if got.to_bits() != want.to_bits() {
+            self.fail(s, &format!("const {:?}", got), &format!("{:?}", want));
+        }
+        let expect = exact(s, want.to_bits(), want.is_infinite(), &format!("{:.1100}", want));
+        let got = parse_exact::<f64>(s.as_bytes()).map(f64::to_bits);
+        if got != expect {
+            self.fail(s, &format!("exact {:?}", got), &format!("{:?}", expect));
+        }
+    }
+
+    fn f32(&mut self, s: &str) {
+        self.inputs += 1;
+        let want: f32 = s.parse().unwrap();
+        match parse::<f32>(s.as_bytes()) {
+            Ok(got) if got.to_bits() == want.to_bits() => {}
+            got => self.fail(s, &format!("{:?}", got), &format!("{:?}", want)),
+        }
+        let got = const_parse_f32(s);
+        if got.to_bits() != want.to_bits() {
+            self.fail(s, &format!("const {:?}", got), &format!("{:?}", want));
+        }
+        let bits = want.to_bits() as u64;
+        let expect = exact(s, bits, want.is_infinite(), &format!("{:.1100}", want));
+        let got = parse_exact::<f32>(s.as_bytes()).map(|f| f.to_bits() as u64);
+        if got != expect {
+            self.fail(s, &format!("exact {:?}", got), &format!("{:?}", expect));
+        }
+    }
+
+    // Values computed at compile time against `parse` at run time.
+    fn constants(&mut self) {
+        for (i, s) in LITERALS.iter().enumerate() {
+            self.inputs += 1;
+            match parse::<f64>(s.as_bytes()) {
+                Ok(want) if want.to_bits() == F64S[i].to_bits() => {}
+                want => self.fail(s, &format!("const {:?}", F64S[i]), &format!("{:?}", want)),
+            }
+            match parse::<f32>(s.as_bytes()) {
+                Ok(want) if want.to_bits() == F32S[i].to_bits() => {}
+                want => self.fail(s, &format!("const {:?}", F32S[i]), &format!("{:?}", want)),
+            }
+        }
+    }
+
+    fn fail(&mut self, s: &str, got: &str, want: &str) {
+        self.fails += 1;
+        if self.fails <= 10 {
+            let shown = if s.len() > 80 { &s[..80] } else { s };
+            println!("{}... ({} digits): got {}, want {}", shown, s.len(), got, want);
+        }
+    }
+
+    // The midpoint of `lo` and `hi`, and variants on either side of it.
+    fn around(&mut self, rng: &mut Rng, lo: f64, hi: f64, check: fn(&mut Self, &str)) {
+        let s = midpoint(lo, hi);
+        check(self, &s);
+        check(self, &truncate(&s, (rng.next() % 800) as usize));
+        check(self, &nudge(&s, (rng.next() % 4) as usize));
+        check(self, &nudge(&s, 800));
+    }
+}
+
+fn main() {
+    let rounds: u64 = std::env::args().nth(1).map_or(20_000, |s| s.parse().unwrap());
+    let mut rng = Rng(0x2545f4914f6cdd1d);
+    let mut check = Check { inputs: 0, fails: 0 };
+    check.constants();
+
+    // Exponents around where the tokenizer stops accumulating them, and far
+    // enough past it to overflow an i32.
+    for mant in ["0", "1", "0.0001", "12345678901234567890123"] {
+        for exp in ["268435455", "268435456", "2147483647", "2684354550", "999999999999999999"] {
+            for sign in ["", "-", "+"] {
+                check.f64(&format!("{}e{}{}", mant, sign, exp));
+                check.f32(&format!("{}e{}{}", mant, sign, exp));
+            }
+        }
+    }
+
+    for _ in 0..rounds {
+        // Any finite f64 and its neighbour, weighted towards subnormals and
+        // values whose halfway points are integers.
+        let bits = match rng.next() % 3 {
+            0 => rng.next() >> 12,
+            1 => 0x4330000000000000 + (rng.next() >> 2) % 0x3cb0000000000000,
+            _ => rng.next() >> 1,
+        };
+        let lo = f64::from_bits(bits % 0x7fefffffffffffff);
+        let hi = f64::from_bits(lo.to_bits() + 1);
+        check.around(&mut rng, lo, hi, Check::f64);
+        check.f64(&format!("{:.*e}", (rng.next() % 40) as usize, lo));
+        check.f64(&expansion(lo));
+
+        // f32 neighbours; their midpoint is exact in f64.
+        let lo = f32::from_bits((rng.next() >> 33) as u32 % 0x7f7fffff);
+        let hi = f32::from_bits(lo.to_bits() + 1);
+        check.around(&mut rng, lo as f64, hi as f64, Check::f32);
+        check.f32(&format!("{:.*e}", (rng.next() % 20) as usize, lo));
+        check.f32(&expansion(lo as f64));
+    }
+
+    println!("{} inputs, {} failures", check.inputs, check.fails);
+    if check.fails != 0 {
+        exit(1);
+    }
+}
@@ -1,19 +1,17 @@
 extern crate float;
 
-use core::ffi::CStr;
-
 fn main() {
-    let d = "1.0902420340782359E+27";
-    let c = unsafe { CStr::from_bytes_with_nul_unchecked(d.as_bytes()) };
+    let c = c"1.0902420340782359E+27";
+    let d = c.to_str().unwrap();
     println!("{:?}", float::ffi::from_bytes_f32_c(c));
 
-    println!("{:?}", float::from_bytes::parse::<f32>(d.as_bytes()));
+    println!("{:?}", float::from_bytes::parse::<f32>(d.as_bytes()).unwrap());
 
-    let d = "1.0902420340782359E+57";
-    let c = unsafe { CStr::from_bytes_with_nul_unchecked(d.as_bytes()) };
+    let c = c"1.0902420340782359E+57";
+    let d = c.to_str().unwrap();
     println!("{:?}", float::ffi::from_bytes_f64_c(c));
 
-    println!("{:?}", float::from_bytes::parse::<f64>(d.as_bytes()));
+    println!("{:?}", float::from_bytes::parse::<f64>(d.as_bytes()).unwrap());
 
     let f: f32 = 1.0902420340782359E+27;
 
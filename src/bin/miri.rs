@@ -0,0 +1,117 @@
+extern crate float;
+
+use std::process::exit;
+
+use float::from_bytes::{parse, parse_complex, parse_padded, parse_si, Padded, PADDING};
+use float::to_bytes::complex::Style;
+
+// A handful of inputs through every path that reads or writes without a
+// bounds check in the default build: each parser tier, the SIMD classifier,
+// the padded reader, and all the formatting buffers. It is small enough to
+// run under Miri, which reports any access outside a buffer:
+//
+//     cargo +nightly miri run --bin miri
+//
+// It also runs natively, and checks its results against the standard
+// library either way.
+
+const INPUTS: [&str; 16] = [
+    "0",
+    "-0.0",
+    "1",
+    "0.1",
+    "123456.789e-3",
+    "9007199254740993",
+    "1.7976931348623157e308",
+    "1e309",
+    "2.2250738585072011e-308",
+    "4.9406564584124654e-324",
+    "2.4703282292062327e-324",
+    "1.00000000000000011102230246251565404236316680908203125",
+    "1.000000000000000111022302462515654042363166809082031250000001",
+    "12345678901234567890123456789012345678901234567890e-30",
+    "3.4028235677973366e38",
+    "1.4012984643e-45",
+];
+
+const INVALID: [&str; 5] = ["", "-", ".", "1e", "1e+"];
+
+const INTEGERS: [i128; 8] = [0, -1, 9, -10, i64::MIN as i128, u64::MAX as i128, i128::MIN, i128::MAX];
+
+fn main() {
+    let mut fails = 0;
+    let mut fail = |what: String| {
+        println!("{}", what);
+        fails += 1;
+    };
+
+    let mut f64s = float::to_bytes::f64::Buffer::new();
+    let mut f32s = float::to_bytes::f32::Buffer::new();
+    for s in INPUTS {
+        let want: f64 = s.parse().unwrap();
+        match parse::<f64>(s.as_bytes()) {
+            Ok(got) if got.to_bits() == want.to_bits() => {}
+            got => fail(format!("parse::<f64>({:?}): got {:?}, want {:?}", s, got, want)),
+        }
+        let want32: f32 = s.parse().unwrap();
+        match parse::<f32>(s.as_bytes()) {
+            Ok(got) if got.to_bits() == want32.to_bits() => {}
+            got => fail(format!("parse::<f32>({:?}): got {:?}, want {:?}", s, got, want32)),
+        }
+
+        // Through a buffer with padding after the number, which the padded
+        // reader scans in whole blocks.
+        let mut buf = s.as_bytes().to_vec();
+        buf.resize(s.len() + PADDING, b' ');
+        let padded = Padded::new(&buf, s.len()).unwrap();
+        match parse_padded::<f64>(padded) {
+            Ok(got) if got.to_bits() == want.to_bits() => {}
+            got => fail(format!("parse_padded({:?}): got {:?}, want {:?}", s, got, want)),
+        }
+
+        for out in [f64s.format(want).to_owned(), f64s.format_eng(want).to_owned()] {
+            if want.is_finite() && parse::<f64>(out.as_bytes()).ok().map(f64::to_bits) != Some(want.to_bits()) {
+                fail(format!("f64 {:?} formats as {:?}", want, out));
+            }
+        }
+        let out = f64s.format_si(want);
+        if want.is_finite() && parse_si::<f64>(out.as_bytes()).ok().map(f64::to_bits) != Some(want.to_bits()) {
+            fail(format!("f64 {:?} formats with a prefix as {:?}", want, out));
+        }
+        let out = f32s.format(want32);
+        if want32.is_finite() && parse::<f32>(out.as_bytes()).ok().map(f32::to_bits) != Some(want32.to_bits()) {
+            fail(format!("f32 {:?} formats as {:?}", want32, out));
+        }
+    }
+    for s in INVALID {
+        if parse::<f64>(s.as_bytes()).is_ok() {
+            fail(format!("parse::<f64>({:?}) accepted", s));
+        }
+    }
+
+    let mut ints = float::to_bytes::int::Buffer::new();
+    for i in INTEGERS {
+        let want = i.to_string();
+        if ints.format(i) != want {
+            fail(format!("{} formats as {:?}", want, ints.format(i)));
+        }
+        let want = (i as u128).to_string();
+        if ints.format(i as u128) != want {
+            fail(format!("{} formats as {:?}", want, ints.format(i as u128)));
+        }
+    }
+
+    let mut complex = float::to_bytes::complex::Buffer::new();
+    let (re, im) = (-f64::MIN_POSITIVE, -1.7976931348623157e308);
+    for style in [Style::I, Style::J, Style::Tuple] {
+        let out = complex.format(re, im, style);
+        if parse_complex::<f64>(out.as_bytes()) != Ok((re, im)) {
+            fail(format!("{:?} + {:?}i formats as {:?}", re, im, out));
+        }
+    }
+
+    if fails != 0 {
+        exit(1);
+    }
+    println!("ok");
+}
@@ -0,0 +1,28 @@
+// Links only if the guarded entry points cannot panic. The check needs the
+// optimizer to see through every call, so build it with fat LTO:
+//
+//     CARGO_PROFILE_RELEASE_LTO=fat cargo build --release --features no-panic --bin no_panic
+//
+// and again with `--features no-panic,limb32` and `--features no-panic,safe`,
+// since 32-bit limbs and checked accesses change which bounds checks the
+// optimizer has to remove.
+extern crate float;
+
+use std::hint::black_box;
+
+fn main() {
+    let d: &[u8] = black_box(b"1.0902420340782359E+27");
+    println!("{:?}", float::from_bytes::parse::<f32>(d));
+    println!("{:?}", float::from_bytes::parse::<f64>(d));
+    println!("{:?}", float::from_bytes::parse_exact::<f32>(d));
+    println!("{:?}", float::from_bytes::parse_exact::<f64>(d));
+
+    let mut b = float::to_bytes::f32::Buffer::new();
+    println!("{}", b.format(black_box(1.0902420340782359E+27)));
+    println!("{}", b.format_eng(black_box(1.0902420340782359E+27)));
+    println!("{}", b.format_si(black_box(1.0902420340782359E+27)));
+    let mut b = float::to_bytes::f64::Buffer::new();
+    println!("{}", b.format(black_box(1.0902420340782359E+57)));
+    println!("{}", b.format_eng(black_box(1.0902420340782359E+57)));
+    println!("{}", b.format_si(black_box(1.0902420340782359E+57)));
+}
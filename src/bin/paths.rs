@@ -0,0 +1,69 @@
+extern crate float;
+
+use std::io::{self, Read};
+use std::process::exit;
+
+use float::from_bytes::{parse, parse_explain, reset_stats, stats, Stats, DIGIT_BUCKETS};
+
+// Reports which conversion paths the numbers in a file take, or how single
+// numbers given as arguments are converted:
+//
+//     cargo run --release --bin paths --features stats [-- --f32] < data.txt
+//     cargo run --release --bin paths --features stats -- --explain 0.1 1e-320
+
+fn print(stats: &Stats) {
+    let share = |n: usize| n as f64 * 100.0 / stats.conversions.max(1) as f64;
+    println!("{} conversions", stats.conversions);
+    for (name, n) in [
+        ("fast", stats.fast),
+        ("moderate", stats.moderate),
+        ("fallback", stats.fallback),
+        ("slow", stats.slow),
+        ("many digits", stats.many_digits),
+    ] {
+        println!("  {:12} {:10} {:6.2}%", name, n, share(n));
+    }
+    println!("significant digits");
+    for (digits, &n) in stats.digits.iter().enumerate().filter(|&(_, &n)| n != 0) {
+        let plus = if digits == DIGIT_BUCKETS - 1 { "+" } else { "" };
+        println!("  {:>3}{:1}        {:10} {:6.2}%", digits, plus, n, share(n));
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let f32 = args.iter().any(|a| a == "--f32");
+
+    if args.iter().any(|a| a == "--explain") {
+        for a in args.iter().filter(|a| !a.starts_with("--")) {
+            if f32 {
+                println!("{}: {:#?}", a, parse_explain::<f32>(a.as_bytes()));
+            } else {
+                println!("{}: {:#?}", a, parse_explain::<f64>(a.as_bytes()));
+            }
+        }
+        return;
+    }
+
+    let mut input = Vec::new();
+    if let Err(err) = io::stdin().read_to_end(&mut input) {
+        eprintln!("cannot read input: {}", err);
+        exit(1);
+    }
+
+    reset_stats::<f32>();
+    reset_stats::<f64>();
+    let mut invalid = 0;
+    for word in input.split(|c| c.is_ascii_whitespace()).filter(|w| !w.is_empty()) {
+        let ok = match f32 {
+            true => parse::<f32>(word).is_ok(),
+            false => parse::<f64>(word).is_ok(),
+        };
+        invalid += !ok as usize;
+    }
+
+    print(&if f32 { stats::<f32>() } else { stats::<f64>() });
+    if invalid != 0 {
+        println!("{} words were not numbers", invalid);
+    }
+}
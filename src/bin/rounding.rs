@@ -0,0 +1,78 @@
+extern crate float;
+
+use std::hint::black_box;
+use std::os::raw::c_int;
+
+extern "C" {
+    fn fesetround(round: c_int) -> c_int;
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+const MODES: [(&str, c_int); 3] = [("upward", 0x800), ("downward", 0x400), ("toward zero", 0xc00)];
+#[cfg(target_arch = "aarch64")]
+const MODES: [(&str, c_int); 3] = [("upward", 0x400000), ("downward", 0x800000), ("toward zero", 0xc00000)];
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+const MODES: [(&str, c_int); 0] = [];
+
+const TO_NEAREST: c_int = 0;
+
+fn inputs() -> Vec<String> {
+    let mut inputs: Vec<String> = [
+        "0.1",
+        "-0.3",
+        "1e22",
+        "123456789e-5",
+        "16777217",
+        "9007199254740993",
+        "1.0000001192092896",
+        "0.000000000000000000000000000000000000011754942807573642917",
+        "4.9406564584124654e-324",
+        "2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324",
+        "3.4028235677973366e38",
+        "1.7976931348623158e308",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+
+    let mut x: u64 = 0x2545f4914f6cdd1d;
+    let mut b = float::to_bytes::f64::Buffer::new();
+    for _ in 0..100000 {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        inputs.push(b.format(f64::from_bits(x >> 2)).to_owned());
+        inputs.push(format!("{}.{:04}", x >> 44, x % 10000));
+        inputs.push(format!("{}e-{}", x >> 40, x % 12));
+        inputs.push(format!("-{}e{}", x >> 45, x % 23));
+    }
+    inputs
+}
+
+fn parse(inputs: &[String]) -> Vec<(u32, u64)> {
+    inputs
+        .iter()
+        .map(|s| {
+            let d = black_box(s.as_bytes());
+            let f32 = float::from_bytes::parse::<f32>(d).unwrap();
+            let f64 = float::from_bytes::parse::<f64>(d).unwrap();
+            (f32.to_bits(), f64.to_bits())
+        })
+        .collect()
+}
+
+// Every mode must give the results the default mode gives.
+fn main() {
+    let inputs = inputs();
+    let expected = parse(&inputs);
+
+    for (name, mode) in MODES {
+        assert_eq!(unsafe { fesetround(mode) }, 0);
+        let actual = parse(&inputs);
+        assert_eq!(unsafe { fesetround(TO_NEAREST) }, 0);
+
+        let mismatches = expected.iter().zip(&actual).filter(|(a, b)| a != b).count();
+        println!("{:12} {} of {} differ", name, mismatches, inputs.len());
+        assert_eq!(mismatches, 0);
+    }
+}
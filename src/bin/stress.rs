@@ -21,7 +21,7 @@ fn main() {
                     // let b: f32 = float::ffi::from_bytes_f32_c(unsafe {
                     //     CStr::from_bytes_with_nul_unchecked(s.as_bytes())
                     // });
-                    let b = float::from_bytes::parse::<f32>(s.as_bytes());
+                    let b = float::from_bytes::parse::<f32>(s.as_bytes()).unwrap();
                     assert!(a == b);
                 }
             }
@@ -41,7 +41,7 @@ fn main() {
             // let b: f32 = float::ffi::from_bytes_f32_c(unsafe {
             //     CStr::from_bytes_with_nul_unchecked(s.as_bytes())
             // });
-            let b = float::from_bytes::parse::<f32>(s.as_bytes());
+            let b = float::from_bytes::parse::<f32>(s.as_bytes()).unwrap();
             assert!(a == b);
         }
     }
@@ -1,19 +1,19 @@
 use core::ffi::{c_char, c_double, c_float, c_uint, CStr};
 
-#[link(name = "float")]
+// Linked from the static library build.rs compiles out of cpp/link.cc.
 extern "C" {
-    fn from_bytes_f32(d: *const c_char) -> c_float;
-    fn from_bytes_f64(d: *const c_char) -> c_double;
+    fn from_bytes_f32(d: *const c_char, len: usize) -> c_float;
+    fn from_bytes_f64(d: *const c_char, len: usize) -> c_double;
 
     fn to_bytes_f32(d: *mut c_char, f: c_float) -> c_uint;
     fn to_bytes_f64(d: *mut c_char, f: c_double) -> c_uint;
 }
 
 pub fn from_bytes_f32_c(d: &CStr) -> f32 {
-    unsafe { from_bytes_f32(d.as_ptr()) }
+    unsafe { from_bytes_f32(d.as_ptr(), d.to_bytes().len()) }
 }
 pub fn from_bytes_f64_c(d: &CStr) -> f64 {
-    unsafe { from_bytes_f64(d.as_ptr()) }
+    unsafe { from_bytes_f64(d.as_ptr(), d.to_bytes().len()) }
 }
 
 pub fn to_bytes_f32_c(d: *mut c_char, f: f32) -> usize {
@@ -0,0 +1,178 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, is_made_of_eight_digits_fast, parse_into_tokens};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchError {
+    // 1-based, like the line numbers of `LineError` and `MatrixError`.
+    pub row: usize,
+    // 0-based, the same numbering as the `columns` of `parse_csv_columns`.
+    pub column: usize,
+    pub offset: usize,
+    pub kind: Error,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {}, column {} (byte {}): {}",
+            self.row, self.column, self.offset, self.kind
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+pub fn parse_delimited<F>(input: &[u8], delimiter: u8, out: &mut Vec<F>) -> Result<(), BatchError>
+where
+    F: Float,
+{
+    parse_rows(input, delimiter, None, out)
+}
+
+// Values come out row by row in the order of `columns`, which may repeat a
+// column.
+pub fn parse_csv_columns<F>(
+    input: &[u8],
+    delimiter: u8,
+    columns: &[usize],
+    out: &mut Vec<F>,
+) -> Result<(), BatchError>
+where
+    F: Float,
+{
+    parse_rows(input, delimiter, Some(columns), out)
+}
+
+fn is_blank(c: u8) -> bool {
+    c == b' ' || c == b'\t' || c == b'\r'
+}
+
+// Digit runs are skipped eight bytes at a time, so only the bytes around
+// '.', the exponent and the delimiter are looked at one by one.
+fn field_end(d: &[u8], mut i: usize, delimiter: u8, whitespace: bool) -> usize {
+    loop {
+        while d.len() - i >= 8 && is_made_of_eight_digits_fast(&d[i..]) {
+            i += 8;
+        }
+        if i == d.len() {
+            return i;
+        }
+        let c = d[i];
+        if c == b'\n' || (whitespace && is_blank(c)) || (!whitespace && c == delimiter) {
+            return i;
+        }
+        i += 1;
+    }
+}
+
+fn parse_rows<F>(
+    input: &[u8],
+    delimiter: u8,
+    columns: Option<&[usize]>,
+    out: &mut Vec<F>,
+) -> Result<(), BatchError>
+where
+    F: Float,
+{
+    let whitespace = is_blank(delimiter);
+    let len = input.len();
+    let mut i = 0;
+    let mut row = 1;
+    // The selected fields of the current row, by position in `columns`.
+    let mut selected: Vec<Option<F>> = Vec::new();
+    selected.resize(columns.map_or(0, <[usize]>::len), None);
+
+    // Every field is kept when no columns are picked, so the input length
+    // bounds the count. Otherwise the first row tells roughly how many rows
+    // follow, and only the picked columns of each are kept.
+    let mut estimate = columns.is_some();
+    if !estimate {
+        out.reserve(len / 8);
+    }
+    while i < len {
+        let row_start = i;
+        let mut column = 0;
+        let mut expect_field = false;
+        loop {
+            while i < len && is_blank(input[i]) {
+                i += 1;
+            }
+            if i == len || input[i] == b'\n' {
+                if expect_field {
+                    return Err(BatchError {
+                        row,
+                        column,
+                        offset: i,
+                        kind: Error::Empty,
+                    });
+                }
+                break;
+            }
+
+            let start = i;
+            i = field_end(input, i, delimiter, whitespace);
+            let mut end = i;
+            while end > start && is_blank(input[end - 1]) {
+                end -= 1;
+            }
+            let field = &input[start..end];
+            let error = |kind| BatchError {
+                row,
+                column,
+                offset: start,
+                kind,
+            };
+
+            if field.is_empty() {
+                return Err(error(Error::Empty));
+            }
+            if columns.is_none_or(|c| c.contains(&column)) {
+                let value = match parse_into_tokens(field) {
+                    Some(tokens) if tokens.len == field.len() => convert::<F>(&tokens),
+                    _ => return Err(error(Error::Invalid)),
+                };
+                match columns {
+                    Some(columns) => {
+                        for (slot, _) in selected.iter_mut().zip(columns).filter(|(_, &c)| c == column) {
+                            *slot = Some(value);
+                        }
+                    }
+                    None => out.push(value),
+                }
+            }
+            column += 1;
+
+            expect_field = !whitespace && i < len && input[i] == delimiter;
+            if expect_field {
+                i += 1;
+            }
+        }
+
+        if let Some(columns) = columns {
+            if column != 0 {
+                if let Some(&missing) = columns.iter().filter(|&&c| c >= column).min() {
+                    return Err(BatchError {
+                        row,
+                        column: missing,
+                        offset: i,
+                        kind: Error::Empty,
+                    });
+                }
+                if estimate {
+                    out.reserve(len / (i + 1 - row_start) * columns.len());
+                    estimate = false;
+                }
+                out.extend(selected.iter_mut().filter_map(Option::take));
+            }
+        }
+        i += 1;
+        row += 1;
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use core::ops;
 
 use crate::from_bytes::table_small::{SMALL_F32_POW10, SMALL_F64_POW10};
+use crate::unchecked::{get_unchecked, unchecked_fn};
 
 pub trait Float:
     Copy
@@ -44,12 +45,20 @@ pub trait Float:
 
     const MAX_EXP_DISGUISED_FAST_PATH: i32;
 
+    // Whether an exact f64 product or a short enough f64 quotient can be
+    // rounded once more to reach this type.
+    const WIDE_FAST_PATH: bool = false;
+
     fn from_u64(u: u64) -> Self;
+    fn from_f64(f: f64) -> Self;
 
     fn from_bits(u: u64) -> Self;
     fn to_bits(self) -> u64;
 
-    unsafe fn pow_fast_path(exponent: usize) -> Self;
+    // `exponent` has to be at most `MAX_EXP_FAST_PATH`.
+    unchecked_fn! {
+        fn pow_fast_path(exponent: usize) -> Self;
+    }
 
     fn is_denormal(self) -> bool {
         self.to_bits() & Self::EXP_MASK == 0
@@ -95,15 +104,22 @@ impl Float for f32 {
     const MIN_EXP_FAST_PATH: i32 = -10;
     const MAX_EXP_FAST_PATH: i32 = 10;
     const MAX_EXP_DISGUISED_FAST_PATH: i32 = 17;
+    const WIDE_FAST_PATH: bool = true;
 
-    unsafe fn pow_fast_path(exponent: usize) -> Self {
-        unsafe { *SMALL_F32_POW10.get_unchecked(exponent) }
+    unchecked_fn! {
+        fn pow_fast_path(exponent: usize) -> Self {
+            get_unchecked!(SMALL_F32_POW10, exponent)
+        }
     }
 
     fn from_u64(u: u64) -> f32 {
         u as _
     }
 
+    fn from_f64(f: f64) -> f32 {
+        f as _
+    }
+
     fn from_bits(u: u64) -> f32 {
         f32::from_bits(u as u32)
     }
@@ -133,14 +149,20 @@ impl Float for f64 {
     const MAX_EXP_FAST_PATH: i32 = 22;
     const MAX_EXP_DISGUISED_FAST_PATH: i32 = 37;
 
-    unsafe fn pow_fast_path(exponent: usize) -> Self {
-        unsafe { *SMALL_F64_POW10.get_unchecked(exponent) }
+    unchecked_fn! {
+        fn pow_fast_path(exponent: usize) -> Self {
+            get_unchecked!(SMALL_F64_POW10, exponent)
+        }
     }
 
     fn from_u64(u: u64) -> f64 {
         u as _
     }
 
+    fn from_f64(f: f64) -> f64 {
+        f
+    }
+
     fn from_bits(u: u64) -> f64 {
         f64::from_bits(u)
     }
@@ -156,14 +178,19 @@ mod private {
     impl Sealed for f64 {}
 }
 
+// A float split into its stored mantissa bits and biased exponent. A
+// negative exponent marks an estimate that still has to be rounded; taking
+// off the `INVALID_FP` bias gives its exponent.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) struct ExtendedFloat {
-    pub(crate) mant: u64,
-    pub(crate) exp: i32,
+pub struct ExtendedFloat {
+    pub mant: u64,
+    pub exp: i32,
+}
+
+pub(crate) const fn extended_to_bits<F: Float>(x: ExtendedFloat) -> u64 {
+    x.mant | (x.exp as u64) << F::MANT_SIZE
 }
 
 pub(crate) fn extended_to_float<F: Float>(x: ExtendedFloat) -> F {
-    let mut word = x.mant;
-    word |= (x.exp as u64) << F::MANT_SIZE;
-    F::from_bits(word)
+    F::from_bits(extended_to_bits::<F>(x))
 }
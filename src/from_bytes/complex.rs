@@ -0,0 +1,121 @@
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, parse_into_tokens};
+
+fn is_unit(c: u8) -> bool {
+    matches!(c, b'i' | b'j' | b'I' | b'J')
+}
+
+fn trim(mut d: &[u8]) -> &[u8] {
+    while let [c, rest @ ..] = d {
+        if !c.is_ascii_whitespace() {
+            break;
+        }
+        d = rest;
+    }
+    while let [rest @ .., c] = d {
+        if !c.is_ascii_whitespace() {
+            break;
+        }
+        d = rest;
+    }
+    d
+}
+
+// The words the complex formatter writes for parts that are not finite.
+fn nonfinite<F: Float>(d: &[u8]) -> Option<F> {
+    match d {
+        [b'i', b'n', b'f', ..] => Some(F::from_bits(F::EXP_MASK)),
+        [b'N', b'a', b'N', ..] => Some(F::from_bits(F::EXP_MASK | F::HIDDEN_BIT_MASK >> 1)),
+        _ => None,
+    }
+}
+
+fn signed<F: Float>(d: &[u8]) -> Option<(F, &[u8])> {
+    let d = match d {
+        [b'+', b'-', ..] => return None,
+        [b'+', rest @ ..] => rest,
+        _ => d,
+    };
+    let (neg, word) = match d {
+        [b'-', rest @ ..] => (true, rest),
+        _ => (false, d),
+    };
+    if let Some(value) = nonfinite::<F>(word) {
+        return Some((if neg { -value } else { value }, &word[3..]));
+    }
+    let tokens = parse_into_tokens(d)?;
+    Some((convert::<F>(&tokens), &d[tokens.len..]))
+}
+
+fn real<F: Float>(d: &[u8]) -> Result<F, Error> {
+    match signed::<F>(d) {
+        Some((value, [])) => Ok(value),
+        _ => Err(Error::Invalid),
+    }
+}
+
+fn one<F: Float>(neg: bool) -> F {
+    let one = F::from_u64(1);
+    if neg {
+        -one
+    } else {
+        one
+    }
+}
+
+fn algebraic<F: Float>(d: &[u8]) -> Result<(F, F), Error> {
+    let zero = F::from_u64(0);
+    match d {
+        [u] if is_unit(*u) => return Ok((zero, one(false))),
+        [s @ (b'+' | b'-'), u] if is_unit(*u) => return Ok((zero, one(*s == b'-'))),
+        _ => {}
+    }
+
+    let (re, rest) = signed::<F>(d).ok_or(Error::Invalid)?;
+    match rest {
+        [] => Ok((re, zero)),
+        [u] if is_unit(*u) => Ok((zero, re)),
+        [s @ (b'+' | b'-'), tail @ ..] => {
+            let neg = *s == b'-';
+            let im = match tail {
+                [u] if is_unit(*u) => one(neg),
+                [b'+' | b'-', ..] => return Err(Error::Invalid),
+                _ => match signed::<F>(tail) {
+                    Some((im, [u])) if is_unit(*u) => {
+                        if neg {
+                            -im
+                        } else {
+                            im
+                        }
+                    }
+                    _ => return Err(Error::Invalid),
+                },
+            };
+            Ok((re, im))
+        }
+        _ => Err(Error::Invalid),
+    }
+}
+
+pub fn parse_complex<F>(d: &[u8]) -> Result<(F, F), Error>
+where
+    F: Float,
+{
+    let d = trim(d);
+    if d.is_empty() {
+        return Err(Error::Empty);
+    }
+    let inner = match d {
+        [b'(', inner @ .., b')'] => trim(inner),
+        _ => return algebraic::<F>(d),
+    };
+    match inner.iter().position(|&c| c == b',') {
+        Some(comma) => {
+            let re = real::<F>(trim(&inner[..comma]))?;
+            let im = real::<F>(trim(&inner[comma + 1..]))?;
+            Ok((re, im))
+        }
+        None => algebraic::<F>(inner),
+    }
+}
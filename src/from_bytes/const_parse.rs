@@ -0,0 +1,41 @@
+use crate::from_bytes::common::{extended_to_bits, Float};
+use crate::from_bytes::moderate::{compute_error, compute_float, is_ambiguous};
+use crate::from_bytes::parse::tokenize;
+use crate::from_bytes::simd::layout_scalar;
+use crate::from_bytes::slow::slow;
+
+// `convert` without the fast path, whose float arithmetic goes through the
+// `Float` trait. The Eisel-Lemire step rounds those inputs correctly too, so
+// the bits are the same as `parse` gives. Unlike `parse`, the whole input
+// has to be the number, and anything else stops compilation.
+const fn convert_bits<F: Float>(d: &[u8]) -> u64 {
+    let tokens = match tokenize(d, layout_scalar(d)) {
+        Some(tokens) if tokens.len == d.len() => tokens,
+        _ if d.is_empty() => panic!("cannot parse number from empty input"),
+        _ => panic!("invalid number syntax"),
+    };
+
+    let mut fp = compute_float::<F>(tokens.exp, tokens.mant);
+    if is_ambiguous::<F>(&tokens, fp) {
+        fp = compute_error::<F>(tokens.exp, tokens.mant);
+    }
+    if fp.exp < 0 {
+        fp.exp -= F::INVALID_FP;
+        fp = slow::<F>(&tokens, fp);
+    }
+
+    let bits = extended_to_bits::<F>(fp);
+    match tokens.neg {
+        true => bits | F::SIGN_MASK,
+        false => bits,
+    }
+}
+
+// Also at the crate root, for `const X: f64 = float::const_parse("0.1")`.
+pub const fn const_parse(s: &str) -> f64 {
+    f64::from_bits(convert_bits::<f64>(s.as_bytes()))
+}
+
+pub const fn const_parse_f32(s: &str) -> f32 {
+    f32::from_bits(convert_bits::<f32>(s.as_bytes()) as u32)
+}
@@ -0,0 +1,30 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    Empty,
+    Invalid,
+    Overflow,
+    TooLong,
+    TooManyDigits,
+    ExponentTooLarge,
+    Inexact,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Error::Empty => "cannot parse number from empty input",
+            Error::Invalid => "invalid number syntax",
+            Error::Overflow => "number too large to fit in target type",
+            Error::TooLong => "input longer than the configured limit",
+            Error::TooManyDigits => "more significant digits than the configured limit",
+            Error::ExponentTooLarge => "exponent magnitude above the configured limit",
+            Error::Inexact => "number not exactly representable in target type",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
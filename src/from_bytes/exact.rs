@@ -0,0 +1,30 @@
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, parse_into_tokens};
+use crate::from_bytes::slow::is_exact;
+use crate::no_panic::guard;
+
+// Like `parse`, but the whole input has to be the number and it has to be
+// representable as is: a value that rounds to infinity is `Overflow`, and
+// one that rounds at all is `Inexact`.
+pub fn parse_exact<F>(d: &[u8]) -> Result<F, Error>
+where
+    F: Float,
+{
+    guard(|| {
+        let tokens = match parse_into_tokens(d) {
+            Some(tokens) if tokens.len == d.len() => tokens,
+            _ if d.is_empty() => return Err(Error::Empty),
+            _ => return Err(Error::Invalid),
+        };
+        let value = convert::<F>(&tokens);
+        let bits = value.to_bits() & !F::SIGN_MASK;
+        if bits == F::EXP_MASK {
+            return Err(Error::Overflow);
+        }
+        if !is_exact::<F>(&tokens, bits) {
+            return Err(Error::Inexact);
+        }
+        Ok(value)
+    })
+}
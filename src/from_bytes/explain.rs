@@ -0,0 +1,112 @@
+use crate::from_bytes::common::{ExtendedFloat, Float};
+use crate::from_bytes::error::Error;
+use crate::from_bytes::limits::significant_digits;
+use crate::from_bytes::parse::{convert_traced, parse_into_tokens, Number};
+use crate::no_panic::guard;
+
+// Hooks `convert` calls as a number moves through the paths. The default
+// methods do nothing, so conversions traced with `()` compile to the same
+// code as before.
+pub(crate) trait Trace {
+    fn tokens(&mut self, _tokens: &Number) {}
+    fn fast(&mut self) {}
+    fn moderate(&mut self, _fp: ExtendedFloat) {}
+    fn fallback(&mut self) {}
+    fn slow(&mut self, _start: ExtendedFloat, _fp: ExtendedFloat) {}
+}
+
+impl Trace for () {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Path {
+    Fast,
+    Moderate,
+    Slow,
+}
+
+// How one input was converted. `moderate` is the result of the
+// Eisel-Lemire step, `slow_start` the estimate the slow path started from and
+// `slow` its result; each is `None` when the conversion never got there.
+// `fallback` is set when the dropped digits made the moderate result
+// ambiguous and it was recomputed for the slow path, as `Stats::fallback`
+// counts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Explain<F> {
+    pub value: F,
+    pub path: Path,
+    pub digits: usize,
+    pub many_digits: bool,
+    pub moderate: Option<ExtendedFloat>,
+    pub fallback: bool,
+    pub slow_start: Option<ExtendedFloat>,
+    pub slow: Option<ExtendedFloat>,
+}
+
+struct Record {
+    path: Path,
+    digits: usize,
+    many_digits: bool,
+    moderate: Option<ExtendedFloat>,
+    fallback: bool,
+    slow_start: Option<ExtendedFloat>,
+    slow: Option<ExtendedFloat>,
+}
+
+impl Trace for Record {
+    fn tokens(&mut self, tokens: &Number) {
+        self.digits = significant_digits(tokens);
+        self.many_digits = tokens.many_digits;
+    }
+
+    fn fast(&mut self) {
+        self.path = Path::Fast;
+    }
+
+    fn moderate(&mut self, fp: ExtendedFloat) {
+        self.path = Path::Moderate;
+        self.moderate = Some(fp);
+    }
+
+    fn fallback(&mut self) {
+        self.fallback = true;
+    }
+
+    fn slow(&mut self, start: ExtendedFloat, fp: ExtendedFloat) {
+        self.path = Path::Slow;
+        self.slow_start = Some(start);
+        self.slow = Some(fp);
+    }
+}
+
+pub fn parse_explain<F>(d: &[u8]) -> Result<Explain<F>, Error>
+where
+    F: Float,
+{
+    guard(|| {
+        let tokens = match parse_into_tokens(d) {
+            Some(tokens) => tokens,
+            None if d.is_empty() => return Err(Error::Empty),
+            None => return Err(Error::Invalid),
+        };
+        let mut record = Record {
+            path: Path::Fast,
+            digits: 0,
+            many_digits: false,
+            moderate: None,
+            fallback: false,
+            slow_start: None,
+            slow: None,
+        };
+        let value = convert_traced::<F, _>(&tokens, &mut record);
+        Ok(Explain {
+            value,
+            path: record.path,
+            digits: record.digits,
+            many_digits: record.many_digits,
+            moderate: record.moderate,
+            fallback: record.fallback,
+            slow_start: record.slow_start,
+            slow: record.slow,
+        })
+    })
+}
@@ -1,6 +1,9 @@
+use core::hint::black_box;
+
 use crate::from_bytes::common::{Float};
 use crate::from_bytes::parse::{Number};
-use crate::from_bytes::table_small::{SMALL_INT_POW10};
+use crate::from_bytes::table_small::{SMALL_F64_POW10, SMALL_INT_POW10, SMALL_INT_POW5};
+use crate::unchecked::{get_unchecked, unchecked};
 
 fn is_fast_path<F: Float>(num: &Number) -> bool {
     F::MIN_EXP_FAST_PATH <= num.exp
@@ -10,23 +13,45 @@ fn is_fast_path<F: Float>(num: &Number) -> bool {
 }
 
 pub(crate) fn fast<F: Float>(num: &Number) -> Option<F> {
+    if !round_to_nearest() {
+        return None;
+    }
+    native::<F>(num).or_else(|| wide::<F>(num))
+}
+
+// Both fast paths round in hardware, under whatever mode the host last set
+// with `fesetround`. A quarter ulp above 1 only rounds back to 1 when
+// rounding to nearest or down, and three quarters only rounds up to the next
+// float when rounding to nearest or up. `black_box` keeps the additions from
+// being folded at compile time, where the mode is always to nearest.
+#[inline]
+fn round_to_nearest() -> bool {
+    let quarter = black_box(f64::EPSILON / 4.0);
+    1.0 + quarter == 1.0 && 1.0 + 3.0 * quarter == 1.0 + f64::EPSILON
+}
+
+fn native<F: Float>(num: &Number) -> Option<F> {
     if is_fast_path::<F>(num) {
         let max_exponent = F::MAX_EXP_FAST_PATH;
+        // SAFETY: `is_fast_path` bounds the exponent between
+        // `MIN_EXP_FAST_PATH` and `MAX_EXP_DISGUISED_FAST_PATH`, so each power
+        // read below is at most `MAX_EXP_FAST_PATH`, and the shift is at most
+        // their difference, well inside the integer table.
         let mut f = if num.exp <= max_exponent {
             let value = F::from_u64(num.mant);
             if num.exp < 0 {
-                value / unsafe { F::pow_fast_path((-num.exp) as _) }
+                value / unchecked! { F::pow_fast_path((-num.exp) as _) }
             } else {
-                value * unsafe { F::pow_fast_path(num.exp as _) }
+                value * unchecked! { F::pow_fast_path(num.exp as _) }
             }
         } else {
             let shift = num.exp - max_exponent;
-            let int_power = unsafe { *SMALL_INT_POW10.get_unchecked(shift as usize) };
+            let int_power = unchecked! { get_unchecked!(SMALL_INT_POW10, shift as usize) };
             let mantissa = num.mant.checked_mul(int_power)?;
             if mantissa > F::MAX_MANTISSA_FAST_PATH {
                 return None;
             }
-            F::from_u64(mantissa) * unsafe { F::pow_fast_path(max_exponent as _) }
+            F::from_u64(mantissa) * unchecked! { F::pow_fast_path(max_exponent as _) }
         };
         if num.neg {
             f = -f;
@@ -36,3 +61,34 @@ pub(crate) fn fast<F: Float>(num: &Number) -> Option<F> {
         None
     }
 }
+
+// A product m * 10^e is exact in f64 while m * 5^e fits in 53 bits, so the
+// only rounding is the final one. A quotient m / 10^k is rounded twice, but
+// for k <= 12 its distance from any midpoint of the narrower type is larger
+// than half an f64 ulp, so the first rounding can never land on one.
+const MAX_MANTISSA_WIDE: u64 = 1 << 53;
+const MAX_DIVISOR_WIDE: i32 = 12;
+
+fn wide<F: Float>(num: &Number) -> Option<F> {
+    if !F::WIDE_FAST_PATH || num.many_digits || num.mant > MAX_MANTISSA_WIDE {
+        return None;
+    }
+    let value = if num.exp < 0 {
+        if num.exp < -MAX_DIVISOR_WIDE {
+            return None;
+        }
+        num.mant as f64 / SMALL_F64_POW10[(-num.exp) as usize]
+    } else {
+        let power = *SMALL_F64_POW10.get(num.exp as usize)?;
+        let exact = num.mant.checked_mul(SMALL_INT_POW5[num.exp as usize])?;
+        if exact > MAX_MANTISSA_WIDE {
+            return None;
+        }
+        num.mant as f64 * power
+    };
+    let mut f = F::from_f64(value);
+    if num.neg {
+        f = -f;
+    }
+    Some(f)
+}
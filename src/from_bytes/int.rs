@@ -0,0 +1,134 @@
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{
+    is_integer, is_made_of_eight_digits_fast, parse_eight_digits_unrolled,
+};
+
+pub trait Integer: Copy + private::Sealed {
+    fn from_bytes_partial(d: &[u8]) -> Result<(Self, usize), Error>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub fn parse_int<I>(d: &[u8]) -> Result<I, Error>
+where
+    I: Integer,
+{
+    let (value, len) = I::from_bytes_partial(d)?;
+    if len != d.len() {
+        return Err(Error::Invalid);
+    }
+    Ok(value)
+}
+
+pub fn parse_int_partial<I>(d: &[u8]) -> Result<(I, usize), Error>
+where
+    I: Integer,
+{
+    I::from_bytes_partial(d)
+}
+
+macro_rules! digits {
+    ($name:ident, $w:ty, $safe_digits:expr) => {
+        fn $name(mut d: &[u8], max: $w) -> Result<($w, usize), Error> {
+            let start = d.len();
+            while !d.is_empty() && d[0] == b'0' {
+                d = &d[1..];
+            }
+            let significant = d.len();
+
+            let mut i: $w = 0;
+            while d.len() >= 8
+                && significant - d.len() + 8 <= $safe_digits
+                && is_made_of_eight_digits_fast(d)
+            {
+                i = i * 100000000 + parse_eight_digits_unrolled(d) as $w;
+                d = &d[8..];
+            }
+            while !d.is_empty() && is_integer(d[0]) && significant - d.len() < $safe_digits {
+                i = i * 10 + (d[0] - b'0') as $w;
+                d = &d[1..];
+            }
+            while !d.is_empty() && is_integer(d[0]) {
+                i = i
+                    .checked_mul(10)
+                    .and_then(|i| i.checked_add((d[0] - b'0') as $w))
+                    .ok_or(Error::Overflow)?;
+                d = &d[1..];
+            }
+
+            if d.len() == start {
+                return Err(Error::Invalid);
+            }
+            if i > max {
+                return Err(Error::Overflow);
+            }
+            Ok((i, start - d.len()))
+        }
+    };
+}
+
+digits!(parse_digits_u64, u64, 19);
+digits!(parse_digits_u128, u128, 38);
+
+macro_rules! unsigned {
+    ($($t:ty => $digits:ident, $w:ty;)*) => {$(
+        impl private::Sealed for $t {}
+
+        impl Integer for $t {
+            fn from_bytes_partial(d: &[u8]) -> Result<(Self, usize), Error> {
+                let (sign, rest) = match d.first() {
+                    None => return Err(Error::Empty),
+                    Some(b'+') => (1, &d[1..]),
+                    Some(_) => (0, d),
+                };
+                let (value, len) = $digits(rest, <$t>::MAX as $w)?;
+                Ok((value as $t, sign + len))
+            }
+        }
+    )*};
+}
+
+macro_rules! signed {
+    ($($t:ty => $digits:ident, $w:ty;)*) => {$(
+        impl private::Sealed for $t {}
+
+        impl Integer for $t {
+            fn from_bytes_partial(d: &[u8]) -> Result<(Self, usize), Error> {
+                let (neg, sign, rest) = match d.first() {
+                    None => return Err(Error::Empty),
+                    Some(b'-') => (true, 1, &d[1..]),
+                    Some(b'+') => (false, 1, &d[1..]),
+                    Some(_) => (false, 0, d),
+                };
+                let max = <$t>::MAX as $w + neg as $w;
+                let (value, len) = $digits(rest, max)?;
+                let value = if neg {
+                    (value as $t).wrapping_neg()
+                } else {
+                    value as $t
+                };
+                Ok((value, sign + len))
+            }
+        }
+    )*};
+}
+
+unsigned! {
+    u8 => parse_digits_u64, u64;
+    u16 => parse_digits_u64, u64;
+    u32 => parse_digits_u64, u64;
+    u64 => parse_digits_u64, u64;
+    usize => parse_digits_u64, u64;
+    u128 => parse_digits_u128, u128;
+}
+
+signed! {
+    i8 => parse_digits_u64, u64;
+    i16 => parse_digits_u64, u64;
+    i32 => parse_digits_u64, u64;
+    i64 => parse_digits_u64, u64;
+    isize => parse_digits_u64, u64;
+    i128 => parse_digits_u128, u128;
+}
@@ -0,0 +1,83 @@
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, parse_into_tokens, Number};
+use crate::from_bytes::slow::scientific_exponent;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Limits {
+    pub max_len: usize,
+    pub max_digits: usize,
+    pub max_exponent: u32,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_len: 1024,
+            max_digits: 800,
+            max_exponent: 400,
+        }
+    }
+}
+
+// The digits from the first nonzero one to the last: zeros at either end
+// only place the point, and cost nothing to round.
+pub(crate) fn significant_digits(tokens: &Number) -> usize {
+    let digits = || tokens.integer.iter().chain(tokens.fraction);
+    let total = tokens.integer.len() + tokens.fraction.len();
+    let leading = digits().take_while(|&&c| c == b'0').count();
+    if leading == total {
+        return 0;
+    }
+    let trailing = digits().rev().take_while(|&&c| c == b'0').count();
+    total - leading - trailing
+}
+
+pub fn parse_with_limits<F>(d: &[u8], limits: &Limits) -> Result<F, Error>
+where
+    F: Float,
+{
+    if d.is_empty() {
+        return Err(Error::Empty);
+    }
+    // Checked before scanning, so the tokenizer never sees more than `max_len` bytes.
+    if d.len() > limits.max_len {
+        return Err(Error::TooLong);
+    }
+    let tokens = parse_into_tokens(d).ok_or(Error::Invalid)?;
+    if tokens.len != d.len() {
+        return Err(Error::Invalid);
+    }
+    if significant_digits(&tokens) > limits.max_digits {
+        return Err(Error::TooManyDigits);
+    }
+    if tokens.mant != 0 {
+        // The tokenizer saturates the written exponent at 0x10000000, which
+        // is below the largest caps; put back whatever it dropped.
+        let written = written_exponent(d);
+        let exponent = scientific_exponent(&tokens) as i64 + written - written.clamp(-0x10000000, 0x10000000);
+        if exponent.unsigned_abs() > limits.max_exponent as u64 {
+            return Err(Error::ExponentTooLarge);
+        }
+    }
+    Ok(convert::<F>(&tokens))
+}
+
+// The exponent of a valid number as written, saturating well past any u32.
+fn written_exponent(d: &[u8]) -> i64 {
+    let at = match d.iter().position(|&c| c == b'e' || c == b'E') {
+        Some(at) => at,
+        None => return 0,
+    };
+    let (neg, digits) = match &d[at + 1..] {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        rest => (false, rest),
+    };
+    let exp = digits.iter().fold(0, |exp: i64, &c| (10 * exp + (c - b'0') as i64).min(1 << 40));
+    if neg {
+        -exp
+    } else {
+        exp
+    }
+}
@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::from_bytes::parse::{convert, parse_into_tokens};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Missing {
+    Nan,
+    Error,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixOptions<'a> {
+    pub delimiter: Option<u8>,
+    pub comment: Option<u8>,
+    pub skip_header: usize,
+    pub missing: &'a [&'a [u8]],
+    pub on_missing: Missing,
+}
+
+impl Default for MatrixOptions<'static> {
+    fn default() -> Self {
+        MatrixOptions {
+            delimiter: None,
+            comment: Some(b'#'),
+            skip_header: 0,
+            missing: &[b"NA", b""],
+            on_missing: Missing::Nan,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Matrix {
+    pub data: Vec<f64>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixErrorKind {
+    Invalid,
+    Missing,
+    Columns { expected: usize, found: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatrixError {
+    // 1-based.
+    pub line: usize,
+    // 0-based, counting the fields of the line.
+    pub column: usize,
+    pub kind: MatrixErrorKind,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            MatrixErrorKind::Invalid => {
+                write!(f, "line {}, column {}: invalid number", self.line, self.column)
+            }
+            MatrixErrorKind::Missing => {
+                write!(f, "line {}, column {}: missing value", self.line, self.column)
+            }
+            MatrixErrorKind::Columns { expected, found } => write!(
+                f,
+                "line {}: expected {} columns, found {}",
+                self.line, expected, found
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MatrixError {}
+
+fn trim(d: &[u8]) -> &[u8] {
+    let start = d.iter().position(|c| !c.is_ascii_whitespace()).unwrap_or(d.len());
+    let end = d.iter().rposition(|c| !c.is_ascii_whitespace()).map_or(start, |i| i + 1);
+    &d[start..end]
+}
+
+fn cell(d: &[u8], options: &MatrixOptions<'_>) -> Result<f64, MatrixErrorKind> {
+    if options.missing.contains(&d) {
+        return match options.on_missing {
+            Missing::Nan => Ok(f64::NAN),
+            Missing::Error => Err(MatrixErrorKind::Missing),
+        };
+    }
+    match parse_into_tokens(d) {
+        Some(tokens) if tokens.len == d.len() => Ok(convert::<f64>(&tokens)),
+        _ => Err(MatrixErrorKind::Invalid),
+    }
+}
+
+pub fn read_matrix(input: &[u8], options: &MatrixOptions<'_>) -> Result<Matrix, MatrixError> {
+    let mut matrix = Matrix::default();
+
+    for (index, line) in input.split(|&c| c == b'\n').enumerate().skip(options.skip_header) {
+        let line = match options.comment {
+            Some(comment) => line.split(|&c| c == comment).next().unwrap_or(line),
+            None => line,
+        };
+        let line = trim(line);
+        if line.is_empty() {
+            continue;
+        }
+
+        let start = matrix.data.len();
+        let mut push = |column: usize, field: &[u8]| match cell(trim(field), options) {
+            Ok(value) => {
+                matrix.data.push(value);
+                Ok(())
+            }
+            Err(kind) => Err(MatrixError {
+                line: index + 1,
+                column,
+                kind,
+            }),
+        };
+        match options.delimiter {
+            Some(delimiter) => {
+                for (column, field) in line.split(|&c| c == delimiter).enumerate() {
+                    push(column, field)?;
+                }
+            }
+            None => {
+                let fields = line.split(|c| c.is_ascii_whitespace()).filter(|f| !f.is_empty());
+                for (column, field) in fields.enumerate() {
+                    push(column, field)?;
+                }
+            }
+        }
+
+        let found = matrix.data.len() - start;
+        if matrix.rows == 0 {
+            matrix.cols = found;
+        } else if found != matrix.cols {
+            return Err(MatrixError {
+                line: index + 1,
+                column: found.min(matrix.cols),
+                kind: MatrixErrorKind::Columns {
+                    expected: matrix.cols,
+                    found,
+                },
+            });
+        }
+        matrix.rows += 1;
+    }
+    Ok(matrix)
+}
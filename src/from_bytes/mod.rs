@@ -1,12 +1,59 @@
+#[cfg(feature = "alloc")]
+mod batch;
 mod bigint;
 mod common;
+mod complex;
+mod const_parse;
+mod error;
+mod exact;
+mod explain;
 mod fast;
+mod int;
+mod limits;
+#[cfg(feature = "alloc")]
+mod matrix;
 mod moderate;
+mod padded;
+#[cfg(feature = "std")]
+mod parallel;
 mod parse;
 mod rounding;
+mod scan;
+mod si;
+mod simd;
 mod slow;
+#[cfg(feature = "stats")]
+mod stats;
+mod stream;
+mod uncertainty;
 
 mod table_moderate;
 mod table_small;
 
+#[cfg(feature = "alloc")]
+pub use self::batch::{parse_csv_columns, parse_delimited, BatchError};
+pub use self::complex::parse_complex;
+pub use self::const_parse::{const_parse, const_parse_f32};
+pub use self::common::ExtendedFloat;
+pub use self::error::Error;
+pub use self::exact::parse_exact;
+pub use self::explain::{parse_explain, Explain, Path};
+pub use self::int::{parse_int, parse_int_partial, Integer};
+pub use self::limits::{parse_with_limits, Limits};
+#[cfg(feature = "alloc")]
+pub use self::matrix::{read_matrix, Matrix, MatrixError, MatrixErrorKind, MatrixOptions, Missing};
+pub use self::padded::{parse_padded, Padded, PADDING};
+#[cfg(feature = "std")]
+pub use self::parallel::{parse_lines_parallel, LineError};
 pub use self::parse::parse;
+pub use self::scan::{scan, scan_with, Boundary, Scan};
+pub use self::si::parse_si;
+#[cfg(feature = "__checks")]
+#[doc(hidden)]
+pub use self::simd::layouts;
+#[cfg(feature = "stats")]
+pub use self::stats::{reset_stats, stats, Stats, DIGIT_BUCKETS};
+#[cfg(feature = "std")]
+pub use self::stream::Reader;
+pub use self::stream::{Stream, StreamError};
+pub use self::uncertainty::parse_uncertainty;
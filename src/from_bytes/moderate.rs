@@ -1,16 +1,29 @@
 use crate::from_bytes::common::{Float, ExtendedFloat};
+use crate::from_bytes::explain::Trace;
 use crate::from_bytes::parse::{Number};
 use crate::from_bytes::table_moderate::{POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
 
-pub(crate) fn moderate<F: Float>(num: &Number) -> ExtendedFloat {
+pub(crate) fn moderate<F: Float, T: Trace>(num: &Number, trace: &mut T) -> ExtendedFloat {
     let mut fp = compute_float::<F>(num.exp, num.mant);
-    if num.many_digits && fp.exp >= 0 && fp != compute_float::<F>(num.exp, num.mant + 1) {
+    trace.moderate(fp);
+    if is_ambiguous::<F>(num, fp) {
+        trace.fallback();
         fp = compute_error::<F>(num.exp, num.mant);
     }
     fp
 }
 
-fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
+// Whether the digits cut off after the first 19 could change `fp`, in
+// which case it is recomputed with `compute_error` for the slow path.
+pub(crate) const fn is_ambiguous<F: Float>(num: &Number, fp: ExtendedFloat) -> bool {
+    if !num.many_digits || fp.exp < 0 {
+        return false;
+    }
+    let up = compute_float::<F>(num.exp, num.mant + 1);
+    fp.mant != up.mant || fp.exp != up.exp
+}
+
+pub(crate) const fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
     let fp_zero = ExtendedFloat { mant: 0, exp: 0 };
     let fp_inf = ExtendedFloat {
         mant: 0,
@@ -65,14 +78,23 @@ fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
     ExtendedFloat { mant, exp: power2 }
 }
 
-fn compute_error<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
+pub(crate) const fn compute_error<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
+    // `moderate` only gets here for exponents `compute_float` accepted, so
+    // this never changes `q`; it lets the table lookup drop its bounds check.
+    let q = if q < F::SMALLEST_POWER_OF_TEN {
+        F::SMALLEST_POWER_OF_TEN
+    } else if q > F::LARGEST_POWER_OF_TEN {
+        F::LARGEST_POWER_OF_TEN
+    } else {
+        q
+    };
     let lz = w.leading_zeros() as i32;
     w <<= lz;
     let hi = compute_product_approx(q, w, F::MANT_SIZE as usize + 3).1;
     compute_error_scaled::<F>(q, hi, lz)
 }
 
-fn compute_error_scaled<F: Float>(q: i32, mut w: u64, lz: i32) -> ExtendedFloat {
+const fn compute_error_scaled<F: Float>(q: i32, mut w: u64, lz: i32) -> ExtendedFloat {
     let hilz = (w >> 63) as i32 ^ 1;
     w <<= hilz;
     let power2 = power(q as i32) + F::EXP_BIAS - hilz - lz - 62;
@@ -83,16 +105,16 @@ fn compute_error_scaled<F: Float>(q: i32, mut w: u64, lz: i32) -> ExtendedFloat
     }
 }
 
-fn power(q: i32) -> i32 {
+const fn power(q: i32) -> i32 {
     (q.wrapping_mul(152_170 + 65536) >> 16) + 63
 }
 
-fn full_mult(a: u64, b: u64) -> (u64, u64) {
+const fn full_mult(a: u64, b: u64) -> (u64, u64) {
     let r = (a as u128) * (b as u128);
     (r as u64, (r >> 64) as u64)
 }
 
-fn compute_product_approx(q: i32, w: u64, precision: usize) -> (u64, u64) {
+const fn compute_product_approx(q: i32, w: u64, precision: usize) -> (u64, u64) {
     let mask = if precision < 64 {
         u64::MAX >> precision
     } else {
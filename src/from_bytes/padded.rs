@@ -0,0 +1,95 @@
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, tokenize, Layout};
+
+pub const PADDING: usize = 16;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Padded<'a> {
+    buf: &'a [u8],
+    len: usize,
+}
+
+impl<'a> Padded<'a> {
+    // The number is `buf[..len]`; everything after it is padding that is
+    // read but never interpreted.
+    pub fn new(buf: &'a [u8], len: usize) -> Option<Self> {
+        if buf.len() < len.checked_add(PADDING)? {
+            return None;
+        }
+        Some(Padded { buf, len })
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        &self.buf[..self.len]
+    }
+
+    // Every offset up to `len` has PADDING readable bytes after it.
+    #[inline]
+    fn read_u64(&self, at: usize) -> u64 {
+        let mut block = [0; 8];
+        block.copy_from_slice(&self.buf[at..at + 8]);
+        u64::from_le_bytes(block)
+    }
+
+    // The digit run at `at`, eight bytes a step. A run that continues into
+    // the padding is cut back to `len`.
+    #[inline]
+    fn run(&self, at: usize) -> usize {
+        let mut pos = at;
+        while pos < self.len {
+            let n = digit_count(self.read_u64(pos)).min(self.len - pos);
+            pos += n;
+            if n < 8 {
+                break;
+            }
+        }
+        pos - at
+    }
+
+    // What the slice classifiers find in `as_bytes()`.
+    #[inline]
+    fn layout(&self) -> Layout {
+        let d = self.as_bytes();
+        let neg = d.first() == Some(&b'-');
+        let mut at = neg as usize;
+        let integer = self.run(at);
+        at += integer;
+        let dot = d.get(at) == Some(&b'.');
+        let mut fraction = 0;
+        if dot {
+            fraction = self.run(at + 1);
+            at += 1 + fraction;
+        }
+        Layout {
+            neg,
+            integer,
+            dot,
+            fraction,
+            exponent: d.get(at).is_some_and(|&c| c | 0x20 == b'e'),
+        }
+    }
+}
+
+pub fn parse_padded<F>(d: Padded) -> Result<F, Error>
+where
+    F: Float,
+{
+    let bytes = d.as_bytes();
+    if bytes.is_empty() {
+        return Err(Error::Empty);
+    }
+    let tokens = tokenize(bytes, d.layout()).ok_or(Error::Invalid)?;
+    if tokens.len != bytes.len() {
+        return Err(Error::Invalid);
+    }
+    Ok(convert::<F>(&tokens))
+}
+
+#[inline]
+fn digit_count(val: u64) -> usize {
+    let non_digits = (val.wrapping_add(0x4646464646464646) | val.wrapping_sub(0x3030303030303030))
+        & 0x8080808080808080;
+    non_digits.trailing_zeros() as usize / 8
+}
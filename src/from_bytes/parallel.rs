@@ -0,0 +1,98 @@
+use core::fmt;
+use std::thread;
+use std::vec::Vec;
+
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, parse_into_tokens};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineError {
+    pub line: usize,
+    pub kind: Error,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl std::error::Error for LineError {}
+
+fn split_lines(input: &[u8], parts: usize) -> Vec<&[u8]> {
+    let mut chunks = Vec::with_capacity(parts);
+    let mut rest = input;
+    for i in (1..parts).rev() {
+        let target = rest.len() / (i + 1);
+        let end = match rest[target..].iter().position(|&c| c == b'\n') {
+            Some(n) => target + n + 1,
+            None => rest.len(),
+        };
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks.push(rest);
+    chunks
+}
+
+fn count_lines(chunk: &[u8]) -> usize {
+    let newlines = chunk.iter().filter(|&&c| c == b'\n').count();
+    newlines + (chunk.last().is_some_and(|&c| c != b'\n')) as usize
+}
+
+fn parse_chunk<F: Float>(chunk: &[u8], out: &mut [F]) -> Result<(), (usize, Error)> {
+    for (i, (line, slot)) in chunk.split(|&c| c == b'\n').zip(out).enumerate() {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            return Err((i, Error::Empty));
+        }
+        match parse_into_tokens(line) {
+            Some(tokens) if tokens.len == line.len() => *slot = convert::<F>(&tokens),
+            _ => return Err((i, Error::Invalid)),
+        }
+    }
+    Ok(())
+}
+
+pub fn parse_lines_parallel<F>(input: &[u8], threads: usize, out: &mut Vec<F>) -> Result<(), LineError>
+where
+    F: Float + Send,
+{
+    let chunks = split_lines(input, threads.max(1));
+    let lines: Vec<usize> = thread::scope(|s| {
+        let handles: Vec<_> = chunks
+            .iter()
+            .map(|&chunk| s.spawn(move || count_lines(chunk)))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    let base = out.len();
+    out.resize(base + lines.iter().sum::<usize>(), F::from_u64(0));
+
+    let results: Vec<_> = thread::scope(|s| {
+        let mut rest = &mut out[base..];
+        let mut handles = Vec::with_capacity(chunks.len());
+        for (&chunk, &n) in chunks.iter().zip(&lines) {
+            let (slots, tail) = rest.split_at_mut(n);
+            rest = tail;
+            handles.push(s.spawn(move || parse_chunk(chunk, slots)));
+        }
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    let mut first_line = 1;
+    for (result, n) in results.into_iter().zip(lines) {
+        if let Err((line, kind)) = result {
+            out.truncate(base);
+            return Err(LineError {
+                line: first_line + line,
+                kind,
+            });
+        }
+        first_line += n;
+    }
+    Ok(())
+}
@@ -1,7 +1,14 @@
+use crate::from_bytes::bigint::try_opt;
 use crate::from_bytes::common::{Float, extended_to_float};
+use crate::from_bytes::error::Error;
+use crate::from_bytes::explain::Trace;
 use crate::from_bytes::fast::{fast};
 use crate::from_bytes::moderate::{moderate};
+use crate::from_bytes::simd::layout;
 use crate::from_bytes::slow::{slow};
+#[cfg(feature = "stats")]
+use crate::from_bytes::stats::Count;
+use crate::no_panic::guard;
 
 #[derive(Default)]
 pub(crate) struct Number<'a> {
@@ -11,21 +18,48 @@ pub(crate) struct Number<'a> {
     pub many_digits: bool,
     pub integer: &'a [u8],
     pub fraction: &'a [u8],
+    pub len: usize,
 }
 
-pub fn parse<'a, F>(d: &'a [u8]) -> F
+pub fn parse<F>(d: &[u8]) -> Result<F, Error>
 where
     F: Float,
 {
-    let tokens = parse_into_tokens(&d).unwrap();
-    if let Some(value) = fast::<F>(&tokens) {
+    guard(|| match parse_into_tokens(d) {
+        Some(tokens) => Ok(convert::<F>(&tokens)),
+        None if d.is_empty() => Err(Error::Empty),
+        None => Err(Error::Invalid),
+    })
+}
+
+pub(crate) fn convert<F>(tokens: &Number) -> F
+where
+    F: Float,
+{
+    #[cfg(feature = "stats")]
+    let mut trace = Count::new::<F>();
+    #[cfg(not(feature = "stats"))]
+    let mut trace = ();
+    convert_traced::<F, _>(tokens, &mut trace)
+}
+
+pub(crate) fn convert_traced<F, T>(tokens: &Number, trace: &mut T) -> F
+where
+    F: Float,
+    T: Trace,
+{
+    trace.tokens(tokens);
+    if let Some(value) = fast::<F>(tokens) {
+        trace.fast();
         return value;
     }
 
-    let mut fp = moderate::<F>(&tokens);
+    let mut fp = moderate::<F, _>(tokens, trace);
     if fp.exp < 0 {
         fp.exp -= F::INVALID_FP;
-        fp = slow::<F>(&tokens, fp);
+        let start = fp;
+        fp = slow::<F>(tokens, fp);
+        trace.slow(start, fp);
     }
 
     let mut f = extended_to_float::<F>(fp);
@@ -35,7 +69,7 @@ where
     f
 }
 
-fn is_integer(c: u8) -> bool {
+pub(crate) const fn is_integer(c: u8) -> bool {
     c >= b'0' && c <= b'9'
 }
 
@@ -46,7 +80,7 @@ fn read_u64(d: &[u8]) -> u64 {
     val
 }
 
-fn parse_eight_digits_unrolled_(mut val: u64) -> u32 {
+pub(crate) const fn parse_eight_digits_unrolled_(mut val: u64) -> u32 {
     let mask = 0x000000ff000000ff;
     let mul1 = 0x000f424000000064;
     let mul2 = 0x0000271000000001;
@@ -73,111 +107,157 @@ pub(crate) fn is_made_of_eight_digits_fast(d: &[u8]) -> bool {
     is_made_of_eight_digits_fast_(read_u64(d))
 }
 
-pub(crate) fn parse_into_tokens(mut d: &[u8]) -> Option<Number> {
-    if d.len() == 0 {
-        return None;
+// `d` holds only digits. Wrapping arithmetic makes eight digits at a time
+// agree with one at a time even once the value no longer fits in a u64.
+const fn accumulate_digits(mut i: u64, mut d: &[u8]) -> u64 {
+    while let Some((eight, rest)) = d.split_first_chunk::<8>() {
+        i = i
+            .wrapping_mul(100000000)
+            .wrapping_add(parse_eight_digits_unrolled_(u64::from_le_bytes(*eight)) as u64);
+        d = rest;
     }
-    let mut out = Number::default();
-    if d[0] == b'-' {
-        out.neg = true;
-        d = &d[1..];
-        if d.len() == 0 {
-            return None;
+    while let [c, rest @ ..] = d {
+        i = i.wrapping_mul(10).wrapping_add((*c - b'0') as u64);
+        d = rest;
+    }
+    i
+}
+
+// Saturates at 0x10000000, which already over- or underflows any float, so
+// a longer exponent cannot wrap around. The sum is kept in an i64 so the
+// last step before the cap cannot overflow either.
+pub(crate) const fn parse_exponent(mut d: &[u8]) -> Option<(i32, &[u8])> {
+    let mut exp_number: i64 = 0;
+    if let [b'e' | b'E', rest @ ..] = d {
+        d = rest;
+        let mut neg_exp = false;
+        if let [b'-', rest @ ..] = d {
+            neg_exp = true;
+            d = rest;
+        } else if let [b'+', rest @ ..] = d {
+            d = rest;
         }
-        if !is_integer(d[0]) && d[0] != b'.' {
+        if !matches!(d, [c, ..] if is_integer(*c)) {
             return None;
         }
-    }
-    let start_digits = d;
-
-    let mut i: u64 = 0;
-    // while d.len() >= 8 && is_made_of_eight_digits_fast(&d[..8]) {
-    //     i = i.wrapping_mul(100000000).wrapping_add(parse_eight_digits_unrolled(&d[..8]) as u64);
-    //     d = &d[8..];
-    // }
-    while d.len() >= 1 && is_integer(d[0]) {
-        i = i.wrapping_mul(10).wrapping_add((d[0] - b'0') as u64);
-        d = &d[1..];
-    }
-    let mut digit_count = (start_digits.len() - d.len()) as i32;
-    out.integer = &start_digits[..digit_count as usize];
-    let mut exponent = 0;
-    if d.len() >= 1 && d[0] == b'.' {
-        d = &d[1..];
-        let before = d;
-        while d.len() >= 8 && is_made_of_eight_digits_fast(&d[..8]) {
-            i = i
-                .wrapping_mul(100000000)
-                .wrapping_add(parse_eight_digits_unrolled(&d[..8]) as u64);
-            d = &d[8..];
+        while let [c, rest @ ..] = d {
+            if !is_integer(*c) {
+                break;
+            }
+            if exp_number < 0x10000000 {
+                exp_number = 10 * exp_number + (*c - b'0') as i64;
+            }
+            d = rest;
+        }
+        if exp_number > 0x10000000 {
+            exp_number = 0x10000000;
         }
-        while d.len() >= 1 && is_integer(d[0]) {
-            i = i.wrapping_mul(10).wrapping_add((d[0] - b'0') as u64);
-            d = &d[1..];
+        if neg_exp {
+            exp_number = -exp_number;
         }
-        exponent = d.len().wrapping_sub(before.len()) as i32;
-        out.fraction = &before[..(before.len() - d.len()) as usize];
+    }
+    Some((exp_number as i32, d))
+}
+
+// Where the parts of a number end, as a classifier reads them off the
+// bytes: a leading '-', the digit run after it, a '.' and the run after
+// that, and whether an exponent marker comes next. `tokenize` checks the
+// grammar and reads the values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Layout {
+    pub neg: bool,
+    pub integer: usize,
+    pub dot: bool,
+    pub fraction: usize,
+    pub exponent: bool,
+}
+
+pub(crate) fn parse_into_tokens(d: &[u8]) -> Option<Number> {
+    tokenize(d, layout(d))
+}
+
+// A const fn, so that `const_parse` reads numbers the same way.
+#[inline]
+pub(crate) const fn tokenize(d: &[u8], layout: Layout) -> Option<Number<'_>> {
+    let mut out = Number {
+        exp: 0,
+        mant: 0,
+        neg: layout.neg,
+        many_digits: false,
+        integer: &[],
+        fraction: &[],
+        len: 0,
+    };
+    let (_, start_digits) = try_opt!(d.split_at_checked(layout.neg as usize));
+    let (integer, mut rest) = try_opt!(start_digits.split_at_checked(layout.integer));
+    out.integer = integer;
+    let mut i = accumulate_digits(0, integer);
+    let mut digit_count = layout.integer as i32;
+    let mut exponent = 0;
+    if layout.dot {
+        let [b'.', after @ ..] = rest else {
+            return None;
+        };
+        let (fraction, after) = try_opt!(after.split_at_checked(layout.fraction));
+        out.fraction = fraction;
+        i = accumulate_digits(i, fraction);
+        rest = after;
+        exponent = -(layout.fraction as i32);
         digit_count -= exponent;
     }
     if digit_count == 0 {
         return None;
     }
     let mut exp_number = 0;
-    if d.len() >= 1 && (b'e' == d[0] || b'E' == d[0]) {
-        d = &d[1..];
-        let mut neg_exp = false;
-        if d.len() >= 1 && b'-' == d[0] {
-            neg_exp = true;
-            d = &d[1..];
-        } else if d.len() >= 1 && b'+' == d[0] {
-            d = &d[1..];
-        }
-        if d.len() == 0 || !is_integer(d[0]) {
-            return None;
-        } else {
-            while d.len() >= 1 && is_integer(d[0]) {
-                if exp_number < 0x10000000 {
-                    exp_number = 10 * exp_number + (d[0] - b'0') as i32;
-                }
-                d = &d[1..];
-            }
-            if neg_exp {
-                exp_number = -exp_number;
-            }
-            exponent += exp_number;
-        }
+    if layout.exponent {
+        (exp_number, rest) = try_opt!(parse_exponent(rest));
+        exponent += exp_number;
     }
+    out.len = d.len() - rest.len();
 
+    out.exp = exponent;
+    out.mant = i;
     if digit_count > 19 {
-        let mut start = start_digits;
-        while start.len() >= 1 && (start[0] == b'0' || start[0] == b'.') {
-            if start[0] == b'0' {
-                digit_count -= 1;
-            }
-            start = &start[1..];
+        truncate(&mut out, start_digits, digit_count, exp_number);
+    }
+    Some(out)
+}
+
+// With more than 19 significant digits the mantissa keeps the first 19 and
+// `many_digits` records that the rest were dropped.
+const fn truncate(out: &mut Number, mut start: &[u8], mut digit_count: i32, exp_number: i32) {
+    while let [c @ (b'0' | b'.'), rest @ ..] = start {
+        if *c == b'0' {
+            digit_count -= 1;
         }
-        if digit_count > 19 {
-            out.many_digits = true;
-            i = 0;
-            d = out.integer;
-            let minimal_nineteen_digit_integer = 1000000000000000000;
-            while i < minimal_nineteen_digit_integer && d.len() >= 1 {
-                i = i * 10 + (d[0] - b'0') as u64;
-                d = &d[1..];
-            }
+        start = rest;
+    }
+    if digit_count <= 19 {
+        return;
+    }
+    out.many_digits = true;
+    let mut i = 0;
+    let mut d = out.integer;
+    let minimal_nineteen_digit_integer = 1000000000000000000;
+    while let [c, rest @ ..] = d {
+        if i >= minimal_nineteen_digit_integer {
+            break;
+        }
+        i = i * 10 + (*c - b'0') as u64;
+        d = rest;
+    }
+    if i >= minimal_nineteen_digit_integer {
+        out.exp = d.len() as i32 + exp_number;
+    } else {
+        d = out.fraction;
+        while let [c, rest @ ..] = d {
             if i >= minimal_nineteen_digit_integer {
-                exponent = d.len() as i32 + exp_number;
-            } else {
-                d = out.fraction;
-                while i < minimal_nineteen_digit_integer && d.len() >= 1 {
-                    i = i * 10 + (d[0] - b'0') as u64;
-                    d = &d[1..];
-                }
-                exponent = d.len().wrapping_sub(out.fraction.len()) as i32 + exp_number;
+                break;
             }
+            i = i * 10 + (*c - b'0') as u64;
+            d = rest;
         }
+        out.exp = d.len().wrapping_sub(out.fraction.len()) as i32 + exp_number;
     }
-    out.exp = exponent;
     out.mant = i;
-    Some(out)
 }
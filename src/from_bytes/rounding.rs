@@ -1,9 +1,19 @@
+use core::cmp;
+
 use crate::from_bytes::common::{Float, ExtendedFloat};
 
-pub(crate) fn round<F, Cb>(fp: &mut ExtendedFloat, cb: Cb)
+// What `round` does with the bits it shifts out: drop them, or round to
+// nearest, ties to even, given how the exact value compares with the point
+// halfway between the two candidates.
+#[derive(Clone, Copy)]
+pub(crate) enum Rule {
+    Down,
+    Nearest(cmp::Ordering),
+}
+
+pub(crate) const fn round<F>(fp: &mut ExtendedFloat, rule: Rule)
 where
     F: Float,
-    Cb: Fn(&mut ExtendedFloat, i32),
 {
     let fp_inf = ExtendedFloat {
         mant: 0,
@@ -14,12 +24,12 @@ where
 
     if -fp.exp >= mantissa_shift {
         let shift = -fp.exp + 1;
-        cb(fp, shift.min(64));
+        apply(fp, if shift < 64 { shift } else { 64 }, rule);
         fp.exp = (fp.mant >= F::HIDDEN_BIT_MASK) as i32;
         return;
     }
 
-    cb(fp, mantissa_shift);
+    apply(fp, mantissa_shift, rule);
 
     let carry_mask = F::CARRY_MASK;
     if fp.mant & carry_mask == carry_mask {
@@ -35,34 +45,24 @@ where
     fp.mant &= F::MANT_MASK;
 }
 
-pub(crate) fn round_nearest_tie_even<Cb>(fp: &mut ExtendedFloat, shift: i32, cb: Cb)
-where
-    Cb: Fn(bool, bool, bool) -> bool,
-{
-    let mask = match shift == 64 {
-        true => u64::MAX,
-        false => (1 << shift) - 1,
-    };
-    let halfway = match shift == 0 {
-        true => 0,
-        false => 1 << (shift - 1),
-    };
-    let truncated_bits = fp.mant & mask;
-    let is_above = truncated_bits > halfway;
-    let is_halfway = truncated_bits == halfway;
-
-    fp.mant = match shift == 64 {
-        true => 0,
-        false => fp.mant >> shift,
-    };
-    fp.exp += shift;
+const fn apply(fp: &mut ExtendedFloat, shift: i32, rule: Rule) {
+    match rule {
+        Rule::Down => round_down(fp, shift),
+        Rule::Nearest(ord) => round_nearest_tie_even(fp, shift, ord),
+    }
+}
 
+const fn round_nearest_tie_even(fp: &mut ExtendedFloat, shift: i32, ord: cmp::Ordering) {
+    round_down(fp, shift);
     let is_odd = fp.mant & 1 == 1;
-
-    fp.mant += cb(is_odd, is_halfway, is_above) as u64;
+    fp.mant += match ord {
+        cmp::Ordering::Greater => 1,
+        cmp::Ordering::Less => 0,
+        cmp::Ordering::Equal => is_odd as u64,
+    };
 }
 
-pub(crate) fn round_down(fp: &mut ExtendedFloat, shift: i32) {
+const fn round_down(fp: &mut ExtendedFloat, shift: i32) {
     fp.mant = match shift == 64 {
         true => 0,
         false => fp.mant >> shift,
@@ -0,0 +1,167 @@
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use crate::from_bytes::common::Float;
+use crate::from_bytes::parse::{convert, is_integer, parse_into_tokens};
+
+// Which neighbours a literal may have. `Any` takes numbers out of the middle
+// of words. `Word` wants the literal to stand on its own: not after a word,
+// not hyphenated to one ("2020-10-19"), and not running into digits or an
+// underscore, even after letters ("0x1F", "1_000"), though a unit made of
+// letters may follow ("12.5ms"). `Strict` also turns the unit away.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Boundary {
+    Any,
+    #[default]
+    Word,
+    Strict,
+}
+
+pub struct Scan<'a, F> {
+    d: &'a [u8],
+    pos: usize,
+    boundary: Boundary,
+    float: PhantomData<F>,
+}
+
+pub fn scan<F>(d: &[u8]) -> Scan<'_, F>
+where
+    F: Float,
+{
+    scan_with::<F>(d, Boundary::default())
+}
+
+pub fn scan_with<F>(d: &[u8], boundary: Boundary) -> Scan<'_, F>
+where
+    F: Float,
+{
+    Scan {
+        d,
+        pos: 0,
+        boundary,
+        float: PhantomData,
+    }
+}
+
+fn is_word(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+fn is_number(c: u8) -> bool {
+    is_integer(c) || c == b'.'
+}
+
+// Whether the text after a literal carries the word on: a digit or an
+// underscore, straight away or after letters, or a hyphen and a digit.
+fn runs_into_word(d: &[u8]) -> bool {
+    let letters = d.iter().take_while(|c| c.is_ascii_alphabetic()).count();
+    match d.get(letters) {
+        Some(&c) if is_integer(c) || c == b'_' => true,
+        Some(b'-') => letters == 0 && d.get(1).is_some_and(|&c| is_integer(c)),
+        _ => false,
+    }
+}
+
+fn starts_number(d: &[u8]) -> bool {
+    match d {
+        [c, ..] if is_integer(*c) => true,
+        [b'.', c, ..] => is_integer(*c),
+        _ => false,
+    }
+}
+
+impl<'a, F> Scan<'a, F> {
+    // The run goes on through the sign of an exponent, so that "x3.1e-4"
+    // does not leave "4" behind.
+    fn skip_run(&mut self) {
+        let d = self.d;
+        while self.pos < d.len() {
+            let c = d[self.pos];
+            let sign = (c == b'-' || c == b'+')
+                && self.pos > 0
+                && matches!(d[self.pos - 1], b'e' | b'E')
+                && self.pos + 1 < d.len()
+                && is_integer(d[self.pos + 1]);
+            if !(is_word(c) || c == b'.' || sign) {
+                break;
+            }
+            self.pos += 1;
+        }
+    }
+}
+
+impl<'a, F> Iterator for Scan<'a, F>
+where
+    F: Float,
+{
+    type Item = (Range<usize>, F);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let d = self.d;
+        while self.pos < d.len() {
+            let mut start = self.pos;
+            if !starts_number(&d[start..]) {
+                self.pos += 1;
+                continue;
+            }
+            let prev = if start > 0 { Some(d[start - 1]) } else { None };
+            if let Some(c) = prev {
+                if self.boundary != Boundary::Any && is_word(c) {
+                    self.skip_run();
+                    continue;
+                }
+                if c == b'.' && start > 1 && is_integer(d[start - 2]) {
+                    self.skip_run();
+                    continue;
+                }
+            }
+            if prev == Some(b'-') && start >= 2 && is_word(d[start - 2]) {
+                if self.boundary != Boundary::Any {
+                    self.skip_run();
+                    continue;
+                }
+            } else if prev == Some(b'-') {
+                start -= 1;
+            }
+
+            // A dangling exponent marker ("12e", "3E+") ends the literal before it.
+            let tokens = match parse_into_tokens(&d[start..]) {
+                Some(tokens) => tokens,
+                None => {
+                    let end = d[start..]
+                        .iter()
+                        .position(|&c| c == b'e' || c == b'E')
+                        .unwrap_or(d.len() - start);
+                    match parse_into_tokens(&d[start..start + end]) {
+                        Some(tokens) => tokens,
+                        None => {
+                            self.pos += 1;
+                            continue;
+                        }
+                    }
+                }
+            };
+            let mut end = start + tokens.len;
+            self.pos = end;
+
+            // Version strings such as "1.2.3" are not floats.
+            if end < d.len() && d[end] == b'.' && end + 1 < d.len() && is_number(d[end + 1]) {
+                self.skip_run();
+                continue;
+            }
+            if self.boundary == Boundary::Strict && end < d.len() && is_word(d[end]) {
+                self.skip_run();
+                continue;
+            }
+            if self.boundary != Boundary::Any && runs_into_word(&d[end..]) {
+                self.skip_run();
+                continue;
+            }
+            if tokens.fraction.is_empty() && d[end - 1] == b'.' {
+                end -= 1;
+            }
+            return Some((start..end, convert::<F>(&tokens)));
+        }
+        None
+    }
+}
@@ -0,0 +1,46 @@
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, parse_into_tokens};
+
+fn prefix_exponent(d: &[u8]) -> Option<i32> {
+    let exp = match d {
+        b"" => 0,
+        b"y" => -24,
+        b"z" => -21,
+        b"a" => -18,
+        b"f" => -15,
+        b"p" => -12,
+        b"n" => -9,
+        // micro: ASCII u, MICRO SIGN and GREEK SMALL LETTER MU
+        b"u" | [0xc2, 0xb5] | [0xce, 0xbc] => -6,
+        b"m" => -3,
+        b"k" => 3,
+        b"M" => 6,
+        b"G" => 9,
+        b"T" => 12,
+        b"P" => 15,
+        b"E" => 18,
+        b"Z" => 21,
+        b"Y" => 24,
+        _ => return None,
+    };
+    Some(exp)
+}
+
+pub fn parse_si<F>(d: &[u8]) -> Result<F, Error>
+where
+    F: Float,
+{
+    if d.is_empty() {
+        return Err(Error::Empty);
+    }
+    // "10E" is ten exa, not a mantissa followed by an empty exponent.
+    let mut tokens = match parse_into_tokens(d) {
+        Some(tokens) => tokens,
+        None if d.ends_with(b"E") => parse_into_tokens(&d[..d.len() - 1]).ok_or(Error::Invalid)?,
+        None => return Err(Error::Invalid),
+    };
+    let exp = prefix_exponent(&d[tokens.len..]).ok_or(Error::Invalid)?;
+    tokens.exp += exp;
+    Ok(convert::<F>(&tokens))
+}
@@ -0,0 +1,276 @@
+use crate::from_bytes::parse::{is_integer, Layout};
+
+// With std the vector paths are picked at run time; without it, only those
+// the target is compiled for are used. The `safe` feature leaves them out.
+#[cfg(all(feature = "std", not(feature = "safe"), any(target_arch = "x86", target_arch = "x86_64")))]
+macro_rules! has_feature {
+    ($feature:tt) => {
+        std::is_x86_feature_detected!($feature)
+    };
+}
+
+#[cfg(all(not(feature = "std"), not(feature = "safe"), any(target_arch = "x86", target_arch = "x86_64")))]
+macro_rules! has_feature {
+    ($feature:tt) => {
+        cfg!(target_feature = $feature)
+    };
+}
+
+// Where the runs of the number at the start of `d` end. The vector paths
+// classify 16 or 32 bytes per step into digits, '.' and exponent markers;
+// whatever is left over at the end is classified a byte at a time, so every
+// path returns the same layout.
+pub(crate) fn layout(d: &[u8]) -> Layout {
+    #[cfg(all(not(feature = "safe"), any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if d.len() >= 32 && has_feature!("avx2") {
+            return unsafe { x86::layout_avx2(d) };
+        }
+        if d.len() >= 16 && has_feature!("ssse3") {
+            return unsafe { x86::layout_ssse3(d) };
+        }
+        if d.len() >= 16 && has_feature!("sse2") {
+            return unsafe { x86::layout_sse2(d) };
+        }
+    }
+    layout_scalar(d)
+}
+
+// `d` through every classifier this host can run, named, so the checks bin
+// can hold the vector paths to the scalar one.
+#[cfg(feature = "__checks")]
+#[doc(hidden)]
+pub fn layouts(d: &[u8], mut each: impl FnMut(&'static str, (bool, usize, bool, usize, bool))) {
+    let mut each = |name, l: Layout| each(name, (l.neg, l.integer, l.dot, l.fraction, l.exponent));
+    each("scalar", layout_scalar(d));
+    each("masks", walk(d, |at| masks_scalar(d, at)));
+    each("dispatch", layout(d));
+    #[cfg(all(not(feature = "safe"), any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if has_feature!("sse2") {
+            each("sse2", unsafe { x86::layout_sse2(d) });
+        }
+        if has_feature!("ssse3") {
+            each("ssse3", unsafe { x86::layout_ssse3(d) });
+        }
+        if has_feature!("avx2") {
+            each("avx2", unsafe { x86::layout_avx2(d) });
+        }
+    }
+}
+
+const fn is_exponent(c: u8) -> bool {
+    c | 0x20 == b'e'
+}
+
+// One byte at a time, and a const fn so that `const_parse` can use it.
+pub(crate) const fn layout_scalar(d: &[u8]) -> Layout {
+    let neg = matches!(d, [b'-', ..]);
+    let mut at = neg as usize;
+    let integer = run_scalar(d, at);
+    at += integer;
+    let dot = at < d.len() && d[at] == b'.';
+    let mut fraction = 0;
+    if dot {
+        fraction = run_scalar(d, at + 1);
+        at += 1 + fraction;
+    }
+    Layout {
+        neg,
+        integer,
+        dot,
+        fraction,
+        exponent: at < d.len() && is_exponent(d[at]),
+    }
+}
+
+const fn run_scalar(d: &[u8], at: usize) -> usize {
+    let mut i = at;
+    while i < d.len() && is_integer(d[i]) {
+        i += 1;
+    }
+    i - at
+}
+
+// Bit `i` of each mask classifies byte `i` of a block of `width` bytes; the
+// bits past the width are clear.
+#[derive(Clone, Copy)]
+struct Masks {
+    digits: u32,
+    dots: u32,
+    exponents: u32,
+    width: usize,
+}
+
+// Up to 32 bytes at `at`, one at a time, for the end of the input.
+fn masks_scalar(d: &[u8], at: usize) -> Masks {
+    let mut masks = Masks {
+        digits: 0,
+        dots: 0,
+        exponents: 0,
+        width: 0,
+    };
+    for (i, &c) in d.get(at..).unwrap_or_default().iter().take(32).enumerate() {
+        let bit = 1 << i;
+        if is_integer(c) {
+            masks.digits |= bit;
+        } else if c == b'.' {
+            masks.dots |= bit;
+        } else if is_exponent(c) {
+            masks.exponents |= bit;
+        }
+        masks.width = i + 1;
+    }
+    masks
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Stop {
+    Dot,
+    Exponent,
+    Other,
+}
+
+// The layout from the masks of the blocks `block` returns for each offset.
+#[inline(always)]
+fn walk(d: &[u8], mut block: impl FnMut(usize) -> Masks) -> Layout {
+    let neg = d.first() == Some(&b'-');
+    let (integer, mut stop) = run(neg as usize, &mut block);
+    let mut layout = Layout {
+        neg,
+        integer,
+        ..Layout::default()
+    };
+    if stop == Stop::Dot {
+        let (fraction, after) = run(neg as usize + integer + 1, &mut block);
+        layout.dot = true;
+        layout.fraction = fraction;
+        stop = after;
+    }
+    layout.exponent = stop == Stop::Exponent;
+    layout
+}
+
+// The length of the digit run at `at`, and what ends it.
+#[inline(always)]
+fn run(at: usize, block: &mut impl FnMut(usize) -> Masks) -> (usize, Stop) {
+    let mut pos = at;
+    loop {
+        let masks = block(pos);
+        let n = (!masks.digits).trailing_zeros() as usize;
+        if n < masks.width {
+            let bit = 1 << n;
+            let stop = if masks.dots & bit != 0 {
+                Stop::Dot
+            } else if masks.exponents & bit != 0 {
+                Stop::Exponent
+            } else {
+                Stop::Other
+            };
+            return (pos + n - at, stop);
+        }
+        if masks.width == 0 {
+            return (pos - at, Stop::Other);
+        }
+        pos += masks.width;
+    }
+}
+
+#[cfg(all(not(feature = "safe"), any(target_arch = "x86", target_arch = "x86_64")))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    use super::{masks_scalar, walk, Masks};
+    use crate::from_bytes::parse::Layout;
+
+    // The '.' and the exponent markers are the same compares on every path;
+    // 'e' and 'E' differ only in bit 5.
+    #[target_feature(enable = "sse2")]
+    unsafe fn masks_sse2(d: &[u8], at: usize) -> Masks {
+        let v = _mm_loadu_si128(d.as_ptr().add(at).cast());
+        let digits = _mm_and_si128(
+            _mm_cmpgt_epi8(v, _mm_set1_epi8(b'0' as i8 - 1)),
+            _mm_cmplt_epi8(v, _mm_set1_epi8(b'9' as i8 + 1)),
+        );
+        let dots = _mm_cmpeq_epi8(v, _mm_set1_epi8(b'.' as i8));
+        let exponents = _mm_cmpeq_epi8(_mm_or_si128(v, _mm_set1_epi8(0x20)), _mm_set1_epi8(b'e' as i8));
+        Masks {
+            digits: _mm_movemask_epi8(digits) as u32,
+            dots: _mm_movemask_epi8(dots) as u32,
+            exponents: _mm_movemask_epi8(exponents) as u32,
+            width: 16,
+        }
+    }
+
+    // Digits are exactly the bytes whose high nibble is 3 and low nibble is
+    // at most 9, so two nibble lookups replace the range compare.
+    #[target_feature(enable = "ssse3")]
+    unsafe fn masks_ssse3(d: &[u8], at: usize) -> Masks {
+        let lo_table = _mm_setr_epi8(1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0);
+        let hi_table = _mm_setr_epi8(0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        let nibble = _mm_set1_epi8(0x0f);
+        let v = _mm_loadu_si128(d.as_ptr().add(at).cast());
+        let lo = _mm_shuffle_epi8(lo_table, _mm_and_si128(v, nibble));
+        let hi = _mm_shuffle_epi8(hi_table, _mm_and_si128(_mm_srli_epi16(v, 4), nibble));
+        let digits = _mm_cmpeq_epi8(_mm_and_si128(lo, hi), _mm_set1_epi8(1));
+        let dots = _mm_cmpeq_epi8(v, _mm_set1_epi8(b'.' as i8));
+        let exponents = _mm_cmpeq_epi8(_mm_or_si128(v, _mm_set1_epi8(0x20)), _mm_set1_epi8(b'e' as i8));
+        Masks {
+            digits: _mm_movemask_epi8(digits) as u32,
+            dots: _mm_movemask_epi8(dots) as u32,
+            exponents: _mm_movemask_epi8(exponents) as u32,
+            width: 16,
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn masks_avx2(d: &[u8], at: usize) -> Masks {
+        let v = _mm256_loadu_si256(d.as_ptr().add(at).cast());
+        let digits = _mm256_andnot_si256(
+            _mm256_cmpgt_epi8(v, _mm256_set1_epi8(b'9' as i8)),
+            _mm256_cmpgt_epi8(v, _mm256_set1_epi8(b'0' as i8 - 1)),
+        );
+        let dots = _mm256_cmpeq_epi8(v, _mm256_set1_epi8(b'.' as i8));
+        let exponents = _mm256_cmpeq_epi8(_mm256_or_si256(v, _mm256_set1_epi8(0x20)), _mm256_set1_epi8(b'e' as i8));
+        Masks {
+            digits: _mm256_movemask_epi8(digits) as u32,
+            dots: _mm256_movemask_epi8(dots) as u32,
+            exponents: _mm256_movemask_epi8(exponents) as u32,
+            width: 32,
+        }
+    }
+
+    // Each block is loaded only when all of it is inside `d`.
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn layout_sse2(d: &[u8]) -> Layout {
+        walk(d, |at| match at + 16 <= d.len() {
+            true => masks_sse2(d, at),
+            false => masks_scalar(d, at),
+        })
+    }
+
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn layout_ssse3(d: &[u8]) -> Layout {
+        walk(d, |at| match at + 16 <= d.len() {
+            true => masks_ssse3(d, at),
+            false => masks_scalar(d, at),
+        })
+    }
+
+    // At most one 16-byte block fits after the last 32-byte one.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn layout_avx2(d: &[u8]) -> Layout {
+        walk(d, |at| {
+            if at + 32 <= d.len() {
+                masks_avx2(d, at)
+            } else if at + 16 <= d.len() {
+                masks_sse2(d, at)
+            } else {
+                masks_scalar(d, at)
+            }
+        })
+    }
+}
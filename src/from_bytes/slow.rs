@@ -1,182 +1,209 @@
 use core::cmp;
 
-use crate::from_bytes::bigint::{Bigint, Limb, LIMB_BITS};
-use crate::from_bytes::common::{Float, ExtendedFloat, extended_to_float};
-use crate::from_bytes::parse::{parse_eight_digits_unrolled, Number};
-use crate::from_bytes::rounding::{round, round_down, round_nearest_tie_even};
+use crate::from_bytes::bigint::{try_opt, Bigint, Limb, LIMB_BITS};
+use crate::from_bytes::common::{Float, ExtendedFloat, extended_to_bits};
+use crate::from_bytes::parse::{parse_eight_digits_unrolled_, Number};
+use crate::from_bytes::rounding::{round, Rule};
 use crate::from_bytes::table_small::{SMALL_INT_POW10};
+use crate::unchecked::{get_unchecked, unchecked};
 
-pub(crate) fn slow<'a, F>(tokens: &Number, fp: ExtendedFloat) -> ExtendedFloat
+const CHUNK: usize = 19;
+
+// In `compare_fraction` the scaled halfway point stays below 2^(q + 64), and
+// q starts at most 1 - DENORMAL_EXP and only shrinks. In `compare_integer`
+// both sides are below 2^(MAX_EXP + MANT_SIZE + 2). Bigints are only ever
+// multiplied by a single limb, in place, so storage for the larger bound is
+// all a type needs and the `None` results below are unreachable.
+struct Capacity<F>(F);
+
+impl<F: Float> Capacity<F> {
+    const FRACTION_BITS: i32 = 1 - F::DENORMAL_EXP + 64;
+    const INTEGER_BITS: i32 = F::MAX_EXP + F::MANT_SIZE + 2;
+    const LIMBS: usize = {
+        let bits = if Self::FRACTION_BITS > Self::INTEGER_BITS {
+            Self::FRACTION_BITS
+        } else {
+            Self::INTEGER_BITS
+        };
+        (bits as usize).div_ceil(LIMB_BITS)
+    };
+    const CHECK: () = assert!(Self::LIMBS <= F64_LIMBS);
+}
+
+// About 40 bytes per bigint for f32 and 150 for f64.
+const F32_LIMBS: usize = Capacity::<f32>::LIMBS;
+const F64_LIMBS: usize = Capacity::<f64>::LIMBS;
+
+// A const fn, like everything it calls, so that `const_parse` shares it.
+pub(crate) const fn slow<F>(tokens: &Number, mut fp: ExtendedFloat) -> ExtendedFloat
 where
     F: Float,
 {
-    let sci_exp = scientific_exponent(&tokens);
+    let () = Capacity::<F>::CHECK;
+    let point = scientific_exponent(tokens) + 1;
 
-    let (bigmant, digits) = parse_mantissa(&tokens, F::MAX_DIGITS);
-    let exponent = sci_exp + 1 - digits as i32;
-    if exponent >= 0 {
-        positive_digit_comp::<F>(bigmant, exponent)
+    let mut b = fp;
+    round::<F>(&mut b, Rule::Down);
+    let theor = bh::<F>(extended_to_bits::<F>(b));
+
+    let digits = Digits::new(tokens);
+    let ord = if Capacity::<F>::LIMBS <= F32_LIMBS {
+        compare::<F32_LIMBS>(digits, point, theor)
     } else {
-        negative_digit_comp::<F>(bigmant, fp, exponent)
-    }
+        compare::<F64_LIMBS>(digits, point, theor)
+    };
+    let ord = match ord {
+        Some(ord) => ord,
+        None => cmp::Ordering::Equal,
+    };
+
+    round::<F>(&mut fp, Rule::Nearest(ord));
+    fp
 }
 
-fn positive_digit_comp<F: Float>(mut bigmant: Bigint, exponent: i32) -> ExtendedFloat {
-    bigmant.pow(10, exponent as u32).unwrap();
+// Whether the input is exactly the finite float with these bits, which
+// `convert` rounded it to.
+pub(crate) fn is_exact<F: Float>(tokens: &Number, bits: u64) -> bool {
+    if tokens.mant == 0 {
+        return true;
+    }
+    let point = scientific_exponent(tokens) + 1;
+    let digits = Digits::new(tokens);
+    let ord = if Capacity::<F>::LIMBS <= F32_LIMBS {
+        compare::<F32_LIMBS>(digits, point, b::<F>(bits))
+    } else {
+        compare::<F64_LIMBS>(digits, point, b::<F>(bits))
+    };
+    matches!(ord, Some(cmp::Ordering::Equal))
+}
 
-    let (mant, is_truncated) = bigmant.hi64();
-    let exp = bigmant.bit_length() as i32 - 64 + F::EXP_BIAS;
-    let mut fp = ExtendedFloat { mant, exp };
+const fn compare<const N: usize>(digits: Digits, point: i32, theor: ExtendedFloat) -> Option<cmp::Ordering> {
+    if point <= CHUNK as i32 {
+        compare_fraction::<N>(digits, point, theor)
+    } else {
+        compare_integer::<N>(digits, point, theor)
+    }
+}
 
-    round::<F, _>(&mut fp, |f, s| {
-        round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
-            is_above || (is_halfway && is_truncated) || (is_odd && is_halfway)
-        });
-    });
-    fp
+// The input is 0.d1 d2 d3 ... * 10^point. Scaling the halfway point by
+// 10^(CHUNK - point) makes its integer part line up with the first CHUNK
+// digits, and since point <= CHUNK the scaled value is H * 5^e / 2^q, whose
+// decimal expansion terminates. Each step compares one chunk of digits with
+// the integer part and multiplies the remaining fraction by 10^CHUNK, so
+// only the digits up to the first difference are ever looked at.
+const fn compare_fraction<const N: usize>(mut digits: Digits, point: i32, theor: ExtendedFloat) -> Option<cmp::Ordering> {
+    let scale = CHUNK as i32 - point;
+    let mut num = Bigint::<N>::from_u64(theor.mant);
+    try_opt!(num.pow(5, scale as u32));
+    let mut q = -(theor.exp + scale);
+    loop {
+        if q < 0 {
+            try_opt!(num.pow(2, -q as u32));
+            q = 0;
+        }
+        let chunk = digits.next_chunk(CHUNK);
+        let integer = match num.split_off_high(q as usize) {
+            Some(integer) => integer,
+            None => return Some(cmp::Ordering::Less),
+        };
+        if chunk != integer {
+            return match chunk > integer {
+                true => Some(cmp::Ordering::Greater),
+                false => Some(cmp::Ordering::Less),
+            };
+        }
+        if num.is_zero() {
+            return match digits.is_zero() {
+                true => Some(cmp::Ordering::Equal),
+                false => Some(cmp::Ordering::Greater),
+            };
+        }
+        if digits.is_zero() {
+            return Some(cmp::Ordering::Less);
+        }
+        try_opt!(num.pow(5, CHUNK as u32));
+        q -= CHUNK as i32;
+    }
 }
 
-fn negative_digit_comp<F: Float>(
-    bigmant: Bigint,
-    mut fp: ExtendedFloat,
-    exponent: i32,
-) -> ExtendedFloat {
-    let mut real_digits = bigmant;
-    let real_exp = exponent;
+// Past 10^CHUNK the halfway point is an integer, so only the integer digits
+// of the input need to become a bigint; the rest can only break a tie.
+const fn compare_integer<const N: usize>(mut digits: Digits, point: i32, theor: ExtendedFloat) -> Option<cmp::Ordering> {
+    debug_assert!(theor.exp >= 0);
+    let step = if LIMB_BITS == 64 { 19 } else { 9 };
 
-    let mut b = fp;
-    round::<F, _>(&mut b, round_down);
-    let b = extended_to_float::<F>(b);
+    let mut integer = Bigint::<N>::new();
+    let mut count = point as usize;
+    while count > 0 {
+        let n = if count < step { count } else { step };
+        // SAFETY: `n` is at most `step`, and the table runs to 10^19.
+        let power = unchecked! { get_unchecked!(SMALL_INT_POW10, n) };
+        try_opt!(add_native(&mut integer, power as Limb, digits.next_chunk(n) as Limb));
+        count -= n;
+    }
 
-    let theor = bh(b);
-    let mut theor_digits = Bigint::from_u64(theor.mant);
-    let theor_exp = theor.exp;
+    let mut halfway = Bigint::<N>::from_u64(theor.mant);
+    try_opt!(halfway.pow(2, theor.exp as u32));
 
-    let binary_exp = theor_exp - real_exp;
-    let halfradix_exp = -real_exp;
-    if halfradix_exp != 0 {
-        theor_digits.pow(5, halfradix_exp as u32).unwrap();
-    }
-    if binary_exp > 0 {
-        theor_digits.pow(2, binary_exp as u32).unwrap();
-    } else if binary_exp < 0 {
-        real_digits.pow(2, -binary_exp as u32).unwrap();
+    match integer.data.compare(&halfway.data) {
+        cmp::Ordering::Equal if !digits.is_zero() => Some(cmp::Ordering::Greater),
+        ord => Some(ord),
     }
-
-    let ord = real_digits.data.cmp(&theor_digits.data);
-    round::<F, _>(&mut fp, |f, s| {
-        round_nearest_tie_even(f, s, |is_odd, _, _| match ord {
-            cmp::Ordering::Greater => true,
-            cmp::Ordering::Less => false,
-            cmp::Ordering::Equal if is_odd => true,
-            cmp::Ordering::Equal => false,
-        });
-    });
-    fp
 }
 
-fn parse_mantissa(tok: &Number, max_digits: usize) -> (Bigint, usize) {
-    let mut counter = 0;
-    let mut count = 0;
-    let mut value: Limb = 0;
-    let mut out = Bigint::new();
-    let step = if LIMB_BITS == 64 { 19 } else { 9 };
+// Significant digits of the input in order, running on into zeros once the
+// integer and fraction parts are used up.
+struct Digits<'a> {
+    integer: &'a [u8],
+    fraction: &'a [u8],
+}
 
-    let mut d = tok.integer;
-    d = skip_zeros(d);
-    while d.len() >= 1 {
-        while d.len() >= 8 && step - counter >= 8 && max_digits - count >= 8 {
-            value = value * 100000000 + parse_eight_digits_unrolled(&d[..8]) as u64;
-            d = &d[8..];
-            counter += 8;
-            count += 8;
-        }
-        while d.len() >= 1 && counter < step && count < max_digits {
-            value = value * 10 + (d[0] - b'0') as Limb;
-            d = &d[1..];
-            counter += 1;
-            count += 1;
-        }
-        if count == max_digits {
-            add_native(
-                &mut out,
-                unsafe { *SMALL_INT_POW10.get_unchecked(counter) },
-                value,
-            );
-            let mut truncated = is_truncated(d);
-            if tok.fraction.len() >= 1 {
-                truncated |= is_truncated(tok.fraction);
-            }
-            if truncated {
-                add_native(&mut out, 10, 1);
-                count += 1;
-            }
-            return (out, count);
-        } else {
-            add_native(
-                &mut out,
-                unsafe { *SMALL_INT_POW10.get_unchecked(counter) },
-                value,
-            );
-            counter = 0;
-            value = 0;
-        }
+impl<'a> Digits<'a> {
+    const fn new(tok: &Number<'a>) -> Self {
+        let integer = skip_zeros(tok.integer);
+        let fraction = match integer.is_empty() {
+            true => skip_zeros(tok.fraction),
+            false => tok.fraction,
+        };
+        Digits { integer, fraction }
     }
 
-    d = tok.fraction;
-    if d.len() >= 1 {
-        if count == 0 {
-            d = skip_zeros(d);
-        }
-        while d.len() >= 1 {
-            while d.len() >= 8 && step - counter >= 8 && max_digits - count >= 8 {
-                value = value * 100000000 + parse_eight_digits_unrolled(&d[..8]) as u64;
-                d = &d[8..];
-                counter += 8;
-                count += 8;
-            }
-            while d.len() >= 1 && counter < step && count < max_digits {
-                value = value * 10 + (d[0] - b'0') as Limb;
-                d = &d[1..];
-                counter += 1;
-                count += 1;
-            }
-            if count == max_digits {
-                add_native(
-                    &mut out,
-                    unsafe { *SMALL_INT_POW10.get_unchecked(counter) },
-                    value,
-                );
-                let truncated = is_truncated(d);
-                if truncated {
-                    add_native(&mut out, 10, 1);
-                    count += 1;
+    const fn next_chunk(&mut self, mut n: usize) -> u64 {
+        let mut value = 0;
+        while n > 0 {
+            let d = match self.integer.is_empty() {
+                true => &mut self.fraction,
+                false => &mut self.integer,
+            };
+            match d.split_first_chunk::<8>() {
+                Some((eight, rest)) if n >= 8 => {
+                    let eight = parse_eight_digits_unrolled_(u64::from_le_bytes(*eight));
+                    value = value * 100000000 + eight as u64;
+                    *d = rest;
+                    n -= 8;
+                }
+                _ => {
+                    let digit = match d.split_first() {
+                        Some((&c, rest)) => {
+                            *d = rest;
+                            c - b'0'
+                        }
+                        None => 0,
+                    };
+                    value = value * 10 + digit as u64;
+                    n -= 1;
                 }
-                return (out, count);
-            } else {
-                add_native(
-                    &mut out,
-                    unsafe { *SMALL_INT_POW10.get_unchecked(counter) },
-                    value,
-                );
-                counter = 0;
-                value = 0;
             }
         }
+        value
     }
 
-    if counter != 0 {
-        add_native(
-            &mut out,
-            unsafe { *SMALL_INT_POW10.get_unchecked(counter) },
-            value,
-        );
+    const fn is_zero(&self) -> bool {
+        !is_truncated(self.integer) && !is_truncated(self.fraction)
     }
-
-    (out, count)
 }
 
-fn scientific_exponent(num: &Number) -> i32 {
+pub(crate) const fn scientific_exponent(num: &Number) -> i32 {
     let mut mant = num.mant;
     let mut exp = num.exp;
     while mant >= 10000 {
@@ -194,60 +221,48 @@ fn scientific_exponent(num: &Number) -> i32 {
     exp as i32
 }
 
-fn b<F: Float>(float: F) -> ExtendedFloat {
-    ExtendedFloat {
-        mant: float.mantissa(),
-        exp: float.exponent(),
+// The float with these bits as an integer mantissa and a power of two, the
+// same split `Float::mantissa` and `Float::exponent` make.
+const fn b<F: Float>(bits: u64) -> ExtendedFloat {
+    let mant = bits & F::MANT_MASK;
+    match bits & F::EXP_MASK {
+        0 => ExtendedFloat {
+            mant,
+            exp: F::DENORMAL_EXP,
+        },
+        exp => ExtendedFloat {
+            mant: mant + F::HIDDEN_BIT_MASK,
+            exp: (exp >> F::MANT_SIZE) as i32 - F::EXP_BIAS,
+        },
     }
 }
 
-fn bh<F: Float>(float: F) -> ExtendedFloat {
-    let fp = b(float);
+const fn bh<F: Float>(bits: u64) -> ExtendedFloat {
+    let fp = b::<F>(bits);
     ExtendedFloat {
         mant: (fp.mant << 1) + 1,
         exp: fp.exp - 1,
     }
 }
 
-fn skip_zeros(mut d: &[u8]) -> &[u8] {
-    while d.len() >= 8 {
-        let mut val = [0; 8];
-        val[..8].copy_from_slice(&d[..8]);
-        let val = u64::from_le_bytes(val);
-        if val != 0x3030303030303030 {
+const fn skip_zeros(mut d: &[u8]) -> &[u8] {
+    while let Some((eight, rest)) = d.split_first_chunk::<8>() {
+        if u64::from_le_bytes(*eight) != 0x3030303030303030 {
             break;
         }
-        d = &d[8..];
+        d = rest;
     }
-    while d.len() >= 1 {
-        if d[0] != b'0' {
-            break;
-        }
-        d = &d[1..];
+    while let [b'0', rest @ ..] = d {
+        d = rest;
     }
     d
 }
 
-fn is_truncated(mut d: &[u8]) -> bool {
-    while d.len() >= 8 {
-        let mut val = [0; 8];
-        val[..8].copy_from_slice(&d[..8]);
-        let val = u64::from_le_bytes(val);
-        if val != 0x3030303030303030 {
-            return true;
-        }
-        d = &d[8..];
-    }
-    while d.len() >= 1 {
-        if d[0] != b'0' {
-            return true;
-        }
-        d = &d[1..];
-    }
-    false
+const fn is_truncated(d: &[u8]) -> bool {
+    !skip_zeros(d).is_empty()
 }
 
-fn add_native(big: &mut Bigint, power: Limb, value: Limb) {
-    big.data.mul_small(power).unwrap();
-    big.data.add_small(value).unwrap();
+const fn add_native<const N: usize>(big: &mut Bigint<N>, power: Limb, value: Limb) -> Option<()> {
+    try_opt!(big.data.mul_small(power));
+    big.data.add_small(value)
 }
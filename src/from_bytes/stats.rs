@@ -0,0 +1,129 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::from_bytes::common::{ExtendedFloat, Float};
+use crate::from_bytes::explain::Trace;
+use crate::from_bytes::limits::significant_digits;
+use crate::from_bytes::parse::Number;
+
+// `digits[n]` counts inputs with `n` significant digits, and the last entry
+// everything longer.
+pub const DIGIT_BUCKETS: usize = 21;
+
+// How many conversions reached each path since the last reset. Every
+// conversion counts towards `fast` or `moderate`, `fallback` counts the
+// moderate results that had to be recomputed, and all of those go on to
+// `slow`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub conversions: usize,
+    pub fast: usize,
+    pub moderate: usize,
+    pub fallback: usize,
+    pub slow: usize,
+    pub many_digits: usize,
+    pub digits: [usize; DIGIT_BUCKETS],
+}
+
+pub(crate) struct Counters {
+    conversions: AtomicUsize,
+    fast: AtomicUsize,
+    moderate: AtomicUsize,
+    fallback: AtomicUsize,
+    slow: AtomicUsize,
+    many_digits: AtomicUsize,
+    digits: [AtomicUsize; DIGIT_BUCKETS],
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Counters {
+            conversions: AtomicUsize::new(0),
+            fast: AtomicUsize::new(0),
+            moderate: AtomicUsize::new(0),
+            fallback: AtomicUsize::new(0),
+            slow: AtomicUsize::new(0),
+            many_digits: AtomicUsize::new(0),
+            digits: [const { AtomicUsize::new(0) }; DIGIT_BUCKETS],
+        }
+    }
+}
+
+static F32: Counters = Counters::new();
+static F64: Counters = Counters::new();
+
+fn counters<F: Float>() -> &'static Counters {
+    if F::MANT_SIZE == <f32 as Float>::MANT_SIZE {
+        &F32
+    } else {
+        &F64
+    }
+}
+
+fn bump(counter: &AtomicUsize) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) struct Count(&'static Counters);
+
+impl Count {
+    pub(crate) fn new<F: Float>() -> Self {
+        Count(counters::<F>())
+    }
+}
+
+impl Trace for Count {
+    fn tokens(&mut self, tokens: &Number) {
+        bump(&self.0.conversions);
+        if tokens.many_digits {
+            bump(&self.0.many_digits);
+        }
+        let digits = significant_digits(tokens).min(DIGIT_BUCKETS - 1);
+        if let Some(counter) = self.0.digits.get(digits) {
+            bump(counter);
+        }
+    }
+
+    fn fast(&mut self) {
+        bump(&self.0.fast);
+    }
+
+    fn moderate(&mut self, _fp: ExtendedFloat) {
+        bump(&self.0.moderate);
+    }
+
+    fn fallback(&mut self) {
+        bump(&self.0.fallback);
+    }
+
+    fn slow(&mut self, _start: ExtendedFloat, _fp: ExtendedFloat) {
+        bump(&self.0.slow);
+    }
+}
+
+// The counters are updated independently, so a snapshot taken while other
+// threads parse may be off by the conversions in flight.
+pub fn stats<F: Float>() -> Stats {
+    let c = counters::<F>();
+    let load = |counter: &AtomicUsize| counter.load(Ordering::Relaxed);
+    let mut digits = [0; DIGIT_BUCKETS];
+    for (d, counter) in digits.iter_mut().zip(&c.digits) {
+        *d = load(counter);
+    }
+    Stats {
+        conversions: load(&c.conversions),
+        fast: load(&c.fast),
+        moderate: load(&c.moderate),
+        fallback: load(&c.fallback),
+        slow: load(&c.slow),
+        many_digits: load(&c.many_digits),
+        digits,
+    }
+}
+
+pub fn reset_stats<F: Float>() {
+    let c = counters::<F>();
+    let counters = [&c.conversions, &c.fast, &c.moderate, &c.fallback, &c.slow, &c.many_digits];
+    for counter in counters.into_iter().chain(&c.digits) {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
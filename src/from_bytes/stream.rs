@@ -0,0 +1,302 @@
+use core::fmt;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, is_integer, parse_into_tokens};
+
+const MAX_STREAM_DIGITS: usize = 769 + 1;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Idle,
+    Sign,
+    Integer,
+    Fraction,
+    ExpMark,
+    ExpSign,
+    Exponent,
+    // The rest of a token that already failed, up to the next delimiter.
+    Skip,
+}
+
+// `offset` counts bytes from the start of everything fed to the stream, and
+// points at the first byte of the token that failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StreamError {
+    pub offset: usize,
+    pub kind: Error,
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "byte {}: {}", self.offset, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StreamError {}
+
+pub struct Stream<F> {
+    state: State,
+    neg: bool,
+    seen_digit: bool,
+    digits: [u8; MAX_STREAM_DIGITS],
+    len: usize,
+    truncated: bool,
+    exp: i64,
+    exp_neg: bool,
+    exp_number: i64,
+    consumed: usize,
+    start: usize,
+    float: PhantomData<F>,
+}
+
+fn is_delimiter(c: u8) -> bool {
+    c.is_ascii_whitespace() || c == b',' || c == b';'
+}
+
+impl<F> Default for Stream<F>
+where
+    F: Float,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> Stream<F>
+where
+    F: Float,
+{
+    pub fn new() -> Self {
+        assert!(F::MAX_DIGITS < MAX_STREAM_DIGITS);
+        Stream {
+            state: State::Idle,
+            neg: false,
+            seen_digit: false,
+            digits: [b'0'; MAX_STREAM_DIGITS],
+            len: 0,
+            truncated: false,
+            exp: 0,
+            exp_neg: false,
+            exp_number: 0,
+            consumed: 0,
+            start: 0,
+            float: PhantomData,
+        }
+    }
+
+    // Emits every number and every error in the chunk in order. A bad token
+    // is reported once and skipped up to the next delimiter, so the numbers
+    // after it still come through.
+    pub fn feed<E>(&mut self, chunk: &[u8], mut emit: E)
+    where
+        E: FnMut(Result<F, StreamError>),
+    {
+        for &c in chunk {
+            if let Some(result) = self.step(c).transpose() {
+                emit(result);
+            }
+        }
+    }
+
+    pub fn finish(&mut self) -> Result<Option<F>, StreamError> {
+        match self.state {
+            State::Idle => Ok(None),
+            State::Skip => {
+                self.reset();
+                Ok(None)
+            }
+            _ => self.complete().map(Some).map_err(|kind| self.error(kind)),
+        }
+    }
+
+    fn step(&mut self, c: u8) -> Result<Option<F>, StreamError> {
+        if self.state == State::Idle {
+            self.start = self.consumed;
+        }
+        self.consumed += 1;
+        self.push(c).map_err(|kind| self.error(kind))
+    }
+
+    fn error(&self, kind: Error) -> StreamError {
+        StreamError {
+            offset: self.start,
+            kind,
+        }
+    }
+
+    fn push(&mut self, c: u8) -> Result<Option<F>, Error> {
+        let next = match (self.state, c) {
+            (State::Idle | State::Skip, _) if is_delimiter(c) => State::Idle,
+            (State::Skip, _) => State::Skip,
+            (State::Idle, b'-') => {
+                self.neg = true;
+                State::Sign
+            }
+            (State::Idle | State::Sign | State::Integer, _) if is_integer(c) => {
+                self.push_digit(c, false);
+                State::Integer
+            }
+            (State::Idle | State::Sign | State::Integer, b'.') => State::Fraction,
+            (State::Fraction, _) if is_integer(c) => {
+                self.push_digit(c, true);
+                State::Fraction
+            }
+            (State::Integer | State::Fraction, b'e' | b'E') if self.seen_digit => State::ExpMark,
+            (State::ExpMark, b'+') => State::ExpSign,
+            (State::ExpMark, b'-') => {
+                self.exp_neg = true;
+                State::ExpSign
+            }
+            (State::ExpMark | State::ExpSign | State::Exponent, _) if is_integer(c) => {
+                if self.exp_number < 0x10000000 {
+                    self.exp_number = 10 * self.exp_number + (c - b'0') as i64;
+                }
+                State::Exponent
+            }
+            (State::Integer | State::Fraction | State::Exponent, _) if is_delimiter(c) => {
+                return self.complete().map(Some);
+            }
+            _ => {
+                self.reset();
+                if !is_delimiter(c) {
+                    self.state = State::Skip;
+                }
+                return Err(Error::Invalid);
+            }
+        };
+        self.state = next;
+        Ok(None)
+    }
+
+    // Digits past `F::MAX_DIGITS` only matter through whether any of them is
+    // nonzero: no halfway point between two floats needs more than that many.
+    fn push_digit(&mut self, c: u8, fraction: bool) {
+        self.seen_digit = true;
+        if self.len == 0 && c == b'0' {
+            self.exp -= fraction as i64;
+        } else if self.len < F::MAX_DIGITS {
+            self.digits[self.len] = c;
+            self.len += 1;
+            self.exp -= fraction as i64;
+        } else {
+            self.truncated |= c != b'0';
+            self.exp += !fraction as i64;
+        }
+    }
+
+    fn complete(&mut self) -> Result<F, Error> {
+        if !self.seen_digit || matches!(self.state, State::ExpMark | State::ExpSign) {
+            self.reset();
+            return Err(Error::Invalid);
+        }
+        if self.len == 0 {
+            self.digits[0] = b'0';
+        }
+        let mut len = self.len.max(1);
+        let mut exp = self.exp;
+        if self.truncated {
+            self.digits[len] = b'1';
+            len += 1;
+            exp -= 1;
+        }
+        if self.exp_neg {
+            exp -= self.exp_number;
+        } else {
+            exp += self.exp_number;
+        }
+
+        let mut tokens = parse_into_tokens(&self.digits[..len]).ok_or(Error::Invalid)?;
+        tokens.neg = self.neg;
+        tokens.exp += exp.clamp(-0x10000000, 0x10000000) as i32;
+        let value = convert::<F>(&tokens);
+        self.reset();
+        Ok(value)
+    }
+
+    fn reset(&mut self) {
+        self.state = State::Idle;
+        self.neg = false;
+        self.seen_digit = false;
+        self.len = 0;
+        self.truncated = false;
+        self.exp = 0;
+        self.exp_neg = false;
+        self.exp_number = 0;
+    }
+}
+
+#[cfg(feature = "std")]
+pub struct Reader<R, F> {
+    inner: R,
+    stream: Stream<F>,
+    buf: [u8; 8192],
+    pos: usize,
+    end: usize,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R, F> Reader<R, F>
+where
+    R: io::Read,
+    F: Float,
+{
+    pub fn new(inner: R) -> Self {
+        Reader {
+            inner,
+            stream: Stream::new(),
+            buf: [0; 8192],
+            pos: 0,
+            end: 0,
+            done: false,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, F> Iterator for Reader<R, F>
+where
+    R: io::Read,
+    F: Float,
+{
+    type Item = io::Result<F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            while self.pos < self.end {
+                let c = self.buf[self.pos];
+                self.pos += 1;
+                match self.stream.step(c) {
+                    Ok(Some(value)) => return Some(Ok(value)),
+                    Ok(None) => {}
+                    Err(err) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, err))),
+                }
+            }
+            match self.inner.read(&mut self.buf) {
+                Ok(0) => {
+                    self.done = true;
+                    return match self.stream.finish() {
+                        Ok(value) => value.map(Ok),
+                        Err(err) => Some(Err(io::Error::new(io::ErrorKind::InvalidData, err))),
+                    };
+                }
+                Ok(n) => {
+                    self.pos = 0;
+                    self.end = n;
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+}
@@ -60,20 +60,3 @@ pub(crate) const SMALL_F64_POW10: [f64; 23] = [
     1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
     1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
 ];
-
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub(crate) const LARGE_POW5: [u32; 10] = [
-    4279965485, 329373468, 4020270615, 2137533757, 4287402176, 1057042919, 1071430142, 2440757623,
-    381945767, 46164893,
-];
-
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub(crate) const LARGE_POW5: [u64; 5] = [
-    1414648277510068013,
-    9180637584431281687,
-    4539964771860779200,
-    10482974169319127550,
-    198276706040285095,
-];
-
-pub(crate) const LARGE_POW5_STEP: u32 = 135;
@@ -0,0 +1,37 @@
+use crate::from_bytes::common::Float;
+use crate::from_bytes::error::Error;
+use crate::from_bytes::parse::{convert, is_integer, parse_exponent, parse_into_tokens};
+
+pub fn parse_uncertainty<F>(d: &[u8]) -> Result<(F, F), Error>
+where
+    F: Float,
+{
+    if d.is_empty() {
+        return Err(Error::Empty);
+    }
+    let mut value = parse_into_tokens(d).ok_or(Error::Invalid)?;
+    if d[..value.len].iter().any(|&c| c == b'e' || c == b'E') {
+        return Err(Error::Invalid);
+    }
+
+    let rest = &d[value.len..];
+    if rest.first() != Some(&b'(') {
+        return Err(Error::Invalid);
+    }
+    let close = rest.iter().position(|&c| c == b')').ok_or(Error::Invalid)?;
+    let digits = &rest[1..close];
+    if digits.is_empty() || !digits.iter().all(|&c| is_integer(c)) {
+        return Err(Error::Invalid);
+    }
+    let (exp, rest) = parse_exponent(&rest[close + 1..]).ok_or(Error::Invalid)?;
+    if !rest.is_empty() {
+        return Err(Error::Invalid);
+    }
+
+    // The parenthesised digits line up with the last digits of the mantissa.
+    let mut uncertainty = parse_into_tokens(digits).ok_or(Error::Invalid)?;
+    uncertainty.exp += exp - value.fraction.len() as i32;
+    value.exp += exp;
+
+    Ok((convert::<F>(&value), convert::<F>(&uncertainty)))
+}
@@ -1,4 +1,19 @@
+#![no_std]
+#![cfg_attr(feature = "safe", deny(unsafe_code))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "cpp")]
+#[allow(unsafe_code)]
 pub mod ffi;
 
 pub mod from_bytes;
 pub mod to_bytes;
+
+pub use from_bytes::const_parse;
+
+mod no_panic;
+mod unchecked;
@@ -0,0 +1,31 @@
+// With the `no-panic` feature, unwinding out of a guarded closure runs
+// `Guard::drop`, which calls a symbol that is never defined. An optimized
+// build therefore only links once the compiler has removed every path by
+// which the closure could panic.
+#[cfg(feature = "no-panic")]
+struct Guard;
+
+#[cfg(feature = "no-panic")]
+#[allow(unsafe_code)]
+impl Drop for Guard {
+    fn drop(&mut self) {
+        extern "C" {
+            #[link_name = "\n\nERROR[float]: a function guarded by the no-panic feature may panic\n\n"]
+            fn trigger() -> !;
+        }
+        unsafe { trigger() }
+    }
+}
+
+#[inline(always)]
+pub(crate) fn guard<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "no-panic")]
+    {
+        let guard = Guard;
+        let result = f();
+        core::mem::forget(guard);
+        result
+    }
+    #[cfg(not(feature = "no-panic"))]
+    f()
+}
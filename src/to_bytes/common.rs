@@ -22,10 +22,16 @@ impl LoHi for u128 {
     }
 }
 
+use crate::to_bytes::cursor::Cursor;
+use crate::unchecked::unchecked_fn;
+
 pub trait Float: Copy + private::Sealed {
     fn is_nonfinite(self) -> bool;
+    fn is_sign_negative(self) -> bool;
     fn format_nonfinite(self) -> &'static str;
-    unsafe fn write_to_buffer(self, buffer: *mut u8) -> usize;
+    unchecked_fn! {
+        fn write_to_buffer(self, buffer: Cursor) -> Cursor;
+    }
 }
 
 mod private {
@@ -62,24 +68,49 @@ pub(crate) fn floor_log10_pow2_minus_log10_4_over_3(e: i32) -> i32 {
 macro_rules! func {
     ($f:ty, $t:ty, $w:ty) => {
         pub struct Buffer {
-            bytes: [mem::MaybeUninit<u8>; MAX_BUFFER_LEN],
+            bytes: [Byte; MAX_BUFFER_LEN],
         }
 
         impl Buffer {
             pub fn new() -> Self {
-                let bytes = [mem::MaybeUninit::<u8>::uninit(); MAX_BUFFER_LEN];
+                let bytes = [EMPTY; MAX_BUFFER_LEN];
                 Buffer { bytes }
             }
 
             pub fn format(&mut self, f: $f) -> &str {
+                crate::no_panic::guard(move || {
+                    if f.is_nonfinite() {
+                        f.format_nonfinite()
+                    } else {
+                        let start = Cursor::new(&mut self.bytes);
+                        // SAFETY: the buffer holds the longest output of
+                        // `to_chars`, which is all the cursor writes.
+                        unchecked! {
+                            let n = f.write_to_buffer(start).offset_from(start);
+                            as_str(&self.bytes, n)
+                        }
+                    }
+                })
+            }
+
+            pub fn format_eng(&mut self, f: $f) -> &str {
+                crate::no_panic::guard(move || self.format_engineering(f, false))
+            }
+
+            pub fn format_si(&mut self, f: $f) -> &str {
+                crate::no_panic::guard(move || self.format_engineering(f, true))
+            }
+
+            fn format_engineering(&mut self, f: $f, si: bool) -> &str {
                 if f.is_nonfinite() {
                     f.format_nonfinite()
                 } else {
-                    unsafe {
-                        let n = f.write_to_buffer(self.bytes.as_mut_ptr().cast());
-                        debug_assert!(n <= self.bytes.len());
-                        let slice = slice::from_raw_parts(self.bytes.as_ptr().cast(), n);
-                        str::from_utf8_unchecked(slice)
+                    let start = Cursor::new(&mut self.bytes);
+                    // SAFETY: engineering notation is never longer than the
+                    // scientific output the buffer is sized for.
+                    unchecked! {
+                        let n = to_chars_engineering(f, si, start).offset_from(start);
+                        as_str(&self.bytes, n)
                     }
                 }
             }
@@ -91,6 +122,10 @@ macro_rules! func {
                 bits & EXPONENT_MASK == EXPONENT_MASK
             }
 
+            fn is_sign_negative(self) -> bool {
+                self.to_bits() & SIGN_MASK != 0
+            }
+
             fn format_nonfinite(self) -> &'static str {
                 let bits = self.to_bits();
                 if bits & MANTISSA_MASK != 0 {
@@ -102,9 +137,10 @@ macro_rules! func {
                 }
             }
 
-            unsafe fn write_to_buffer(self, buffer: *mut u8) -> usize {
-                let end = to_chars(self, buffer);
-                end.offset_from(buffer) as usize
+            unchecked_fn! {
+                fn write_to_buffer(self, buffer: Cursor) -> Cursor {
+                    to_chars(self, buffer)
+                }
             }
         }
 
@@ -188,7 +224,9 @@ macro_rules! func {
             let include_right_endpoint = has_even_mantissa_bits;
 
             let minus_k = floor_log10_pow2(exponent) - KAPPA as i32;
-            let cache = unsafe { get(-minus_k) };
+            // SAFETY: `minus_k` comes from an exponent of this type, whose
+            // range the cache covers.
+            let cache = unchecked! { get(-minus_k) };
             let beta = exponent + floor_log2_pow10(-minus_k);
 
             let deltai = compute_delta(cache, beta);
@@ -249,7 +287,9 @@ macro_rules! func {
             let minus_k = floor_log10_pow2_minus_log10_4_over_3(exponent);
             let beta = exponent + floor_log2_pow10(-minus_k);
 
-            let cache = unsafe { get(-minus_k) };
+            // SAFETY: `minus_k` comes from an exponent of this type, whose
+            // range the cache covers.
+            let cache = unchecked! { get(-minus_k) };
 
             let mut xi = compute_left_endpoint_for_shorter_interval_case(cache, beta);
             let mut zi = compute_right_endpoint_for_shorter_interval_case(cache, beta);
@@ -344,22 +384,48 @@ macro_rules! func {
             )
         }
 
-        unsafe fn to_chars(x: $f, mut buffer: *mut u8) -> *mut u8 {
-            let br = x.to_bits();
-            let exponent_bits = extract_exponent_bits(br);
-            let s = remove_exponent_bits(br, exponent_bits);
+        unchecked_fn! {
+            fn to_chars(x: $f, mut buffer: Cursor) -> Cursor {
+                let br = x.to_bits();
+                let exponent_bits = extract_exponent_bits(br);
+                let s = remove_exponent_bits(br, exponent_bits);
+
+                if is_negative(s) {
+                    buffer.set(0, b'-');
+                    buffer = buffer.add(1);
+                }
 
-            if is_negative(s) {
-                *buffer = b'-';
-                buffer = buffer.add(1);
+                if is_nonzero(br) {
+                    let (significand, exponent) = to_decimal(x);
+                    to_buffer(significand, exponent, buffer)
+                } else {
+                    buffer.write(b"0E0");
+                    buffer.add(3)
+                }
             }
+        }
 
-            if is_nonzero(br) {
-                let (significand, exponent) = to_decimal(x);
-                to_buffer(significand, exponent, buffer)
-            } else {
-                ptr::copy_nonoverlapping(b"0E0".as_ptr(), buffer, 3);
-                buffer.add(3)
+        unchecked_fn! {
+            fn to_chars_engineering(x: $f, si: bool, mut buffer: Cursor) -> Cursor {
+                let br = x.to_bits();
+                let exponent_bits = extract_exponent_bits(br);
+                let s = remove_exponent_bits(br, exponent_bits);
+
+                if is_negative(s) {
+                    buffer.set(0, b'-');
+                    buffer = buffer.add(1);
+                }
+
+                if is_nonzero(br) {
+                    let (significand, exponent) = to_decimal(x);
+                    write_engineering(significand as u64, exponent, si, buffer)
+                } else if si {
+                    buffer.set(0, b'0');
+                    buffer.add(1)
+                } else {
+                    buffer.write(b"0E0");
+                    buffer.add(3)
+                }
             }
         }
     };
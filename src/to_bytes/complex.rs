@@ -0,0 +1,76 @@
+use crate::to_bytes::common::Float;
+use crate::to_bytes::cursor::{as_str, Byte, Cursor, EMPTY};
+use crate::unchecked::{unchecked, unchecked_fn};
+
+const MAX_PART_LEN: usize = 1 + 17 + 1 + 1 + 1 + 3;
+const MAX_BUFFER_LEN: usize = 1 + MAX_PART_LEN + 1 + MAX_PART_LEN + 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
+    I,
+    J,
+    Tuple,
+}
+
+pub struct Buffer {
+    bytes: [Byte; MAX_BUFFER_LEN],
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Buffer {
+    pub fn new() -> Self {
+        let bytes = [EMPTY; MAX_BUFFER_LEN];
+        Buffer { bytes }
+    }
+
+    pub fn format<F: Float>(&mut self, re: F, im: F, style: Style) -> &str {
+        let start = Cursor::new(&mut self.bytes);
+        let mut buf = start;
+        // SAFETY: the buffer holds two parts of the longest length either
+        // type formats to, and the three bytes around them.
+        unchecked! {
+            if style == Style::Tuple {
+                buf.set(0, b'(');
+                buf = write_part(re, buf.add(1));
+                buf.set(0, b',');
+                buf = write_part(im, buf.add(1));
+                buf.set(0, b')');
+                buf = buf.add(1);
+            } else {
+                buf = write_part(re, buf);
+                // The sign the imaginary part is written with, if any, is
+                // the separator; a NaN is written without one.
+                let im_start = buf.add(1);
+                let im_end = write_part(im, im_start);
+                if im_start.get(0) == b'-' {
+                    im_start.copy_to(buf, im_end.offset_from(im_start));
+                    buf = im_end.sub(1);
+                } else {
+                    buf.set(0, b'+');
+                    buf = im_end;
+                }
+                buf.set(0, if style == Style::I { b'i' } else { b'j' });
+                buf = buf.add(1);
+            }
+            let n = buf.offset_from(start);
+            as_str(&self.bytes, n)
+        }
+    }
+}
+
+unchecked_fn! {
+    fn write_part<F: Float>(f: F, buf: Cursor) -> Cursor {
+        if f.is_nonfinite() {
+            let s = f.format_nonfinite();
+            buf.write(s.as_bytes());
+            buf.add(s.len())
+        } else {
+            f.write_to_buffer(buf)
+        }
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(feature = "safe")]
+use core::cell::Cell;
+#[cfg(not(feature = "safe"))]
+use core::{marker::PhantomData, mem, ptr, slice};
+use core::str;
+
+// A write position in one of the formatting buffers. Every buffer is sized
+// for the longest output of its formatter, and the formatters never move
+// before where they start. Without the `safe` feature that sizing is the
+// only bound on the writes, so every method but `new` is an `unsafe fn`
+// whose caller vouches for the room. With it every access is checked, and
+// one out of bounds reads a zero or writes nothing rather than panic, which
+// keeps the formatters panic-free for the `no-panic` check.
+#[cfg(not(feature = "safe"))]
+#[derive(Clone, Copy)]
+pub struct Cursor<'a> {
+    ptr: *mut u8,
+    buf: PhantomData<&'a mut [Byte]>,
+}
+
+#[cfg(feature = "safe")]
+#[derive(Clone, Copy)]
+pub struct Cursor<'a> {
+    buf: &'a [Cell<u8>],
+    pos: usize,
+}
+
+#[cfg(not(feature = "safe"))]
+pub(crate) type Byte = mem::MaybeUninit<u8>;
+#[cfg(not(feature = "safe"))]
+pub(crate) const EMPTY: Byte = mem::MaybeUninit::uninit();
+
+#[cfg(feature = "safe")]
+pub(crate) type Byte = u8;
+#[cfg(feature = "safe")]
+pub(crate) const EMPTY: Byte = 0;
+
+#[cfg(not(feature = "safe"))]
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(buf: &'a mut [Byte]) -> Self {
+        Cursor {
+            ptr: buf.as_mut_ptr().cast(),
+            buf: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn add(self, n: usize) -> Self {
+        Cursor {
+            ptr: self.ptr.add(n),
+            buf: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn sub(self, n: usize) -> Self {
+        Cursor {
+            ptr: self.ptr.sub(n),
+            buf: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn offset_from(self, start: Self) -> usize {
+        self.ptr.offset_from(start.ptr) as usize
+    }
+
+    #[inline]
+    pub(crate) unsafe fn get(self, i: usize) -> u8 {
+        *self.ptr.add(i)
+    }
+
+    #[inline]
+    pub(crate) unsafe fn set(self, i: usize, b: u8) {
+        *self.ptr.add(i) = b
+    }
+
+    #[inline]
+    pub(crate) unsafe fn write(self, bytes: &[u8]) {
+        ptr::copy_nonoverlapping(bytes.as_ptr(), self.ptr, bytes.len())
+    }
+
+    // Writes `len` bytes of `table` starting at `at`.
+    #[inline]
+    pub(crate) unsafe fn copy_from(self, table: &[u8], at: usize, len: usize) {
+        debug_assert!(at + len <= table.len());
+        ptr::copy_nonoverlapping(table.as_ptr().add(at), self.ptr, len)
+    }
+
+    #[inline]
+    pub(crate) unsafe fn copy_to(self, dst: Self, len: usize) {
+        ptr::copy(self.ptr, dst.ptr, len)
+    }
+
+    #[inline]
+    pub(crate) unsafe fn fill(self, b: u8, len: usize) {
+        ptr::write_bytes(self.ptr, b, len)
+    }
+}
+
+#[cfg(feature = "safe")]
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(buf: &'a mut [Byte]) -> Self {
+        Cursor {
+            buf: Cell::from_mut(buf).as_slice_of_cells(),
+            pos: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add(self, n: usize) -> Self {
+        Cursor {
+            buf: self.buf,
+            pos: self.pos + n,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn sub(self, n: usize) -> Self {
+        Cursor {
+            buf: self.buf,
+            pos: self.pos - n,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn offset_from(self, start: Self) -> usize {
+        self.pos - start.pos
+    }
+
+    #[inline]
+    pub(crate) fn get(self, i: usize) -> u8 {
+        self.buf.get(self.pos + i).map_or(0, Cell::get)
+    }
+
+    #[inline]
+    pub(crate) fn set(self, i: usize, b: u8) {
+        if let Some(byte) = self.buf.get(self.pos + i) {
+            byte.set(b);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn write(self, bytes: &[u8]) {
+        for (i, &b) in bytes.iter().enumerate() {
+            self.set(i, b);
+        }
+    }
+
+    // Writes `len` bytes of `table` starting at `at`.
+    #[inline]
+    pub(crate) fn copy_from(self, table: &[u8], at: usize, len: usize) {
+        self.write(table.get(at..at + len).unwrap_or_default());
+    }
+
+    #[inline]
+    pub(crate) fn copy_to(self, dst: Self, len: usize) {
+        if dst.pos > self.pos {
+            for i in (0..len).rev() {
+                dst.set(i, self.get(i));
+            }
+        } else {
+            for i in 0..len {
+                dst.set(i, self.get(i));
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn fill(self, b: u8, len: usize) {
+        for i in 0..len {
+            self.set(i, b);
+        }
+    }
+}
+
+// The first `len` bytes of a buffer a cursor has written.
+#[cfg(not(feature = "safe"))]
+pub(crate) unsafe fn as_str(bytes: &[Byte], len: usize) -> &str {
+    debug_assert!(len <= bytes.len());
+    str::from_utf8_unchecked(slice::from_raw_parts(bytes.as_ptr().cast(), len))
+}
+
+#[cfg(feature = "safe")]
+pub(crate) fn as_str(bytes: &[Byte], len: usize) -> &str {
+    str::from_utf8(bytes.get(..len).unwrap_or_default()).unwrap_or_default()
+}
@@ -1,12 +1,12 @@
-use core::{mem, ptr, slice, str};
-
 use crate::to_bytes::{
     common::{
         floor_log10_pow2, floor_log10_pow2_minus_log10_4_over_3, floor_log2_pow10, func, Float,
         LoHi, INFINITY, NAN, NEG_INFINITY,
     },
-    to_chars::write_f64 as to_buffer,
+    cursor::{as_str, Byte, Cursor, EMPTY},
+    to_chars::{write_engineering, write_f64 as to_buffer},
 };
+use crate::unchecked::{get_unchecked, unchecked, unchecked_fn};
 
 const EXPONENT_MASK: u64 = 0x7ff0000000000000;
 const MANTISSA_MASK: u64 = 0x000fffffffffffff;
@@ -36,9 +36,11 @@ type Wide = u128;
 const MIN_K: i32 = -292;
 const MAX_K: i32 = 326;
 
-unsafe fn get(k: i32) -> Wide {
-    debug_assert!(k >= MIN_K && k <= MAX_K);
-    *CACHE.get_unchecked((k - MIN_K) as usize)
+unchecked_fn! {
+    fn get(k: i32) -> Wide {
+        debug_assert!(k >= MIN_K && k <= MAX_K);
+        get_unchecked!(CACHE, (k - MIN_K) as usize)
+    }
 }
 
 static CACHE: [Wide; 619] = [
@@ -0,0 +1,95 @@
+use crate::to_bytes::cursor::{as_str, Byte, Cursor, EMPTY};
+use crate::to_bytes::to_chars::{write_u128, write_u64};
+use crate::unchecked::{unchecked, unchecked_fn};
+
+const MAX_BUFFER_LEN: usize = 1 + 39;
+
+pub trait Integer: Copy + private::Sealed {
+    unchecked_fn! {
+        /// # Safety
+        ///
+        /// `buffer` must have room for 40 bytes.
+        fn write_to_buffer(self, buffer: Cursor) -> Cursor;
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub struct Buffer {
+    bytes: [Byte; MAX_BUFFER_LEN],
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Buffer {
+    pub fn new() -> Self {
+        let bytes = [EMPTY; MAX_BUFFER_LEN];
+        Buffer { bytes }
+    }
+
+    pub fn format<I: Integer>(&mut self, i: I) -> &str {
+        let start = Cursor::new(&mut self.bytes);
+        // SAFETY: the buffer has the 40 bytes `write_to_buffer` needs.
+        unchecked! {
+            let n = i.write_to_buffer(start).offset_from(start);
+            as_str(&self.bytes, n)
+        }
+    }
+}
+
+macro_rules! unsigned {
+    ($($t:ty => $write:ident, $w:ty;)*) => {$(
+        impl private::Sealed for $t {}
+
+        impl Integer for $t {
+            unchecked_fn! {
+                fn write_to_buffer(self, buffer: Cursor) -> Cursor {
+                    $write(self as $w, buffer)
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! signed {
+    ($($t:ty => $write:ident, $w:ty;)*) => {$(
+        impl private::Sealed for $t {}
+
+        impl Integer for $t {
+            unchecked_fn! {
+                fn write_to_buffer(self, buffer: Cursor) -> Cursor {
+                    let mut buf = buffer;
+                    if self < 0 {
+                        buf.set(0, b'-');
+                        buf = buf.add(1);
+                    }
+                    $write(self.unsigned_abs() as $w, buf)
+                }
+            }
+        }
+    )*};
+}
+
+unsigned! {
+    u8 => write_u64, u64;
+    u16 => write_u64, u64;
+    u32 => write_u64, u64;
+    u64 => write_u64, u64;
+    usize => write_u64, u64;
+    u128 => write_u128, u128;
+}
+
+signed! {
+    i8 => write_u64, u64;
+    i16 => write_u64, u64;
+    i32 => write_u64, u64;
+    i64 => write_u64, u64;
+    isize => write_u64, u64;
+    i128 => write_u128, u128;
+}
@@ -1,4 +1,7 @@
 mod common;
+pub mod complex;
+mod cursor;
 pub mod f32;
 pub mod f64;
+pub mod int;
 mod to_chars;
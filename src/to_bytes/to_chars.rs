@@ -1,4 +1,5 @@
-use core::ptr;
+use crate::to_bytes::cursor::Cursor;
+use crate::unchecked::{get_unchecked, unchecked_fn};
 
 const RADIX_100_TABLE: [u8; 200] = [
     b'0', b'0', b'0', b'1', b'0', b'2', b'0', b'3', b'0', b'4',
@@ -45,242 +46,191 @@ const RADIX_100_HEAD_TABLE: [u8; 200] = [
     b'9', b'.', b'9', b'.', b'9', b'.', b'9', b'.', b'9', b'.',
 ];
 
-unsafe fn write_1_digit(n: u32, buf: *mut u8) {
-    ptr::copy_nonoverlapping(RADIX_100_TABLE.as_ptr().add(n as usize * 2 + 1), buf, 1);
+unchecked_fn! {
+    fn write_1_digit(n: u32, buf: Cursor) {
+        buf.copy_from(&RADIX_100_TABLE, n as usize * 2 + 1, 1);
+    }
 }
 
-unsafe fn write_2_digits(n: u32, buf: *mut u8) {
-    ptr::copy_nonoverlapping(RADIX_100_TABLE.as_ptr().add(n as usize * 2), buf, 2);
+unchecked_fn! {
+    fn write_2_digits(n: u32, buf: Cursor) {
+        buf.copy_from(&RADIX_100_TABLE, n as usize * 2, 2);
+    }
 }
 
-unsafe fn write_9_digits(mant: u32, mut exp: i32, mut buf: *mut u8) -> (i32, *mut u8) {
-    if mant >= 1_0000_0000 {
-        let mut prod = (mant as u64) * 1441151882;
-        prod >>= 25;
-        ptr::copy_nonoverlapping(
-            RADIX_100_HEAD_TABLE.as_ptr().add((prod >> 32) as usize * 2),
-            buf,
-            2,
-        );
-
-        prod = ((prod as u32) as u64) * 100;
-        write_2_digits((prod >> 32) as u32, buf.add(2));
-        prod = ((prod as u32) as u64) * 100;
-        write_2_digits((prod >> 32) as u32, buf.add(4));
-        prod = ((prod as u32) as u64) * 100;
-        write_2_digits((prod >> 32) as u32, buf.add(6));
-        prod = ((prod as u32) as u64) * 100;
-        write_2_digits((prod >> 32) as u32, buf.add(8));
-
-        exp += 8;
-        buf = buf.add(10);
-    } else if mant >= 100_0000 {
-        let mut prod = (mant as u64) * 281474978;
-        prod >>= 16;
-        let head_digits = (prod >> 32) as u32;
-        exp += 6 + if head_digits >= 10 { 1 } else { 0 };
-
-        ptr::copy_nonoverlapping(
-            RADIX_100_HEAD_TABLE.as_ptr().add(head_digits as usize * 2),
-            buf,
-            2,
-        );
-        *buf.add(2) = *RADIX_100_TABLE.get_unchecked(head_digits as usize * 2 + 1);
-
-        if prod as u32 <= (((1 as u64) << 32) / 100_0000) as u32 {
-            buf = buf.add(
-                1 + ((if head_digits >= 10 { 1 } else { 0 })
-                    & (if *buf.add(2) > b'0' { 1 } else { 0 }))
-                    * 2,
-            );
-        } else {
-            buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
+unchecked_fn! {
+    fn write_9_digits(mant: u32, mut exp: i32, mut buf: Cursor) -> (i32, Cursor) {
+        if mant >= 1_0000_0000 {
+            let mut prod = (mant as u64) * 1441151882;
+            prod >>= 25;
+            buf.copy_from(&RADIX_100_HEAD_TABLE, (prod >> 32) as usize * 2, 2);
 
             prod = ((prod as u32) as u64) * 100;
             write_2_digits((prod >> 32) as u32, buf.add(2));
+            prod = ((prod as u32) as u64) * 100;
+            write_2_digits((prod >> 32) as u32, buf.add(4));
+            prod = ((prod as u32) as u64) * 100;
+            write_2_digits((prod >> 32) as u32, buf.add(6));
+            prod = ((prod as u32) as u64) * 100;
+            write_2_digits((prod >> 32) as u32, buf.add(8));
 
-            if prod as u32 <= (((1 as u64) << 32) / 1_0000) as u32 {
-                buf = buf.add(3 + (if *buf.add(3) > b'0' { 1 } else { 0 }));
+            exp += 8;
+            buf = buf.add(10);
+        } else if mant >= 100_0000 {
+            let mut prod = (mant as u64) * 281474978;
+            prod >>= 16;
+            let head_digits = (prod >> 32) as u32;
+            exp += 6 + if head_digits >= 10 { 1 } else { 0 };
+
+            buf.copy_from(&RADIX_100_HEAD_TABLE, head_digits as usize * 2, 2);
+            buf.set(2, get_unchecked!(RADIX_100_TABLE, head_digits as usize * 2 + 1));
+
+            if prod as u32 <= (((1 as u64) << 32) / 100_0000) as u32 {
+                buf = buf.add(
+                    1 + ((if head_digits >= 10 { 1 } else { 0 })
+                        & (if buf.get(2) > b'0' { 1 } else { 0 }))
+                        * 2,
+                );
             } else {
+                buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
+
                 prod = ((prod as u32) as u64) * 100;
-                write_2_digits((prod >> 32) as u32, buf.add(4));
+                write_2_digits((prod >> 32) as u32, buf.add(2));
 
-                if prod as u32 <= (((1 as u64) << 32) / 100) as u32 {
-                    buf = buf.add(5 + (if *buf.add(5) > b'0' { 1 } else { 0 }));
+                if prod as u32 <= (((1 as u64) << 32) / 1_0000) as u32 {
+                    buf = buf.add(3 + (if buf.get(3) > b'0' { 1 } else { 0 }));
                 } else {
                     prod = ((prod as u32) as u64) * 100;
-                    write_2_digits((prod >> 32) as u32, buf.add(6));
+                    write_2_digits((prod >> 32) as u32, buf.add(4));
+
+                    if prod as u32 <= (((1 as u64) << 32) / 100) as u32 {
+                        buf = buf.add(5 + (if buf.get(5) > b'0' { 1 } else { 0 }));
+                    } else {
+                        prod = ((prod as u32) as u64) * 100;
+                        write_2_digits((prod >> 32) as u32, buf.add(6));
 
-                    buf = buf.add(7 + (if *buf.add(7) > b'0' { 1 } else { 0 }));
+                        buf = buf.add(7 + (if buf.get(7) > b'0' { 1 } else { 0 }));
+                    }
                 }
             }
-        }
-    } else if mant >= 1_0000 {
-        let mut prod = (mant as u64) * 429497;
-        let head_digits = (prod >> 32) as u32;
+        } else if mant >= 1_0000 {
+            let mut prod = (mant as u64) * 429497;
+            let head_digits = (prod >> 32) as u32;
 
-        exp += 4 + (if head_digits >= 10 { 1 } else { 0 });
+            exp += 4 + (if head_digits >= 10 { 1 } else { 0 });
 
-        ptr::copy_nonoverlapping(
-            RADIX_100_HEAD_TABLE.as_ptr().add(head_digits as usize * 2),
-            buf,
-            2,
-        );
-        *buf.add(2) = *RADIX_100_TABLE.get_unchecked(head_digits as usize * 2 + 1);
+            buf.copy_from(&RADIX_100_HEAD_TABLE, head_digits as usize * 2, 2);
+            buf.set(2, get_unchecked!(RADIX_100_TABLE, head_digits as usize * 2 + 1));
 
-        if prod as u32 <= (((1 as u64) << 32) / 1_0000) as u32 {
-            buf = buf.add(
-                1 + ((if head_digits >= 10 { 1 } else { 0 })
-                    & (if *buf.add(2) > b'0' { 1 } else { 0 }))
-                    * 2,
-            );
-        } else {
-            buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
+            if prod as u32 <= (((1 as u64) << 32) / 1_0000) as u32 {
+                buf = buf.add(
+                    1 + ((if head_digits >= 10 { 1 } else { 0 })
+                        & (if buf.get(2) > b'0' { 1 } else { 0 }))
+                        * 2,
+                );
+            } else {
+                buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
 
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(2));
+                prod = ((prod as u32) as u64) * 100;
+                write_2_digits((prod >> 32) as u32, buf.add(2));
+
+                if prod as u32 <= (((1 as u64) << 32) / 100) as u32 {
+                    buf = buf.add(3 + (if buf.get(3) > b'0' { 1 } else { 0 }));
+                } else {
+                    prod = ((prod as u32) as u64) * 100;
+                    write_2_digits((prod >> 32) as u32, buf.add(4));
+
+                    buf = buf.add(5 + (if buf.get(5) > b'0' { 1 } else { 0 }));
+                }
+            }
+        } else if mant >= 100 {
+            let mut prod = (mant as u64) * 42949673;
+            let head_digits = (prod >> 32) as u32;
+
+            exp += 2 + (if head_digits >= 10 { 1 } else { 0 });
+
+            buf.copy_from(&RADIX_100_HEAD_TABLE, head_digits as usize * 2, 2);
+            buf.set(2, get_unchecked!(RADIX_100_TABLE, head_digits as usize * 2 + 1));
 
             if prod as u32 <= (((1 as u64) << 32) / 100) as u32 {
-                buf = buf.add(3 + (if *buf.add(3) > b'0' { 1 } else { 0 }));
+                buf = buf.add(
+                    1 + ((if head_digits >= 10 { 1 } else { 0 })
+                        & (if buf.get(2) > b'0' { 1 } else { 0 }))
+                        * 2,
+                );
             } else {
+                buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
+
                 prod = ((prod as u32) as u64) * 100;
-                write_2_digits((prod >> 32) as u32, buf.add(4));
+                write_2_digits((prod >> 32) as u32, buf.add(2));
 
-                buf = buf.add(5 + (if *buf.add(5) > b'0' { 1 } else { 0 }));
+                buf = buf.add(3 + (if buf.get(3) > b'0' { 1 } else { 0 }));
             }
-        }
-    } else if mant >= 100 {
-        let mut prod = (mant as u64) * 42949673;
-        let head_digits = (prod >> 32) as u32;
-
-        exp += 2 + (if head_digits >= 10 { 1 } else { 0 });
+        } else {
+            exp += if mant >= 10 { 1 } else { 0 };
 
-        ptr::copy_nonoverlapping(
-            RADIX_100_HEAD_TABLE.as_ptr().add(head_digits as usize * 2),
-            buf,
-            2,
-        );
-        *buf.add(2) = *RADIX_100_TABLE.get_unchecked(head_digits as usize * 2 + 1);
+            buf.copy_from(&RADIX_100_HEAD_TABLE, mant as usize * 2, 2);
+            buf.set(2, get_unchecked!(RADIX_100_TABLE, mant as usize * 2 + 1));
 
-        if prod as u32 <= (((1 as u64) << 32) / 100) as u32 {
             buf = buf.add(
-                1 + ((if head_digits >= 10 { 1 } else { 0 })
-                    & (if *buf.add(2) > b'0' { 1 } else { 0 }))
-                    * 2,
+                1 + ((if mant >= 10 { 1 } else { 0 }) & (if buf.get(2) > b'0' { 1 } else { 0 })) * 2,
             );
-        } else {
-            buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
-
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(2));
-
-            buf = buf.add(3 + (if *buf.add(3) > b'0' { 1 } else { 0 }));
         }
-    } else {
-        exp += if mant >= 10 { 1 } else { 0 };
-
-        ptr::copy_nonoverlapping(RADIX_100_HEAD_TABLE.as_ptr().add(mant as usize * 2), buf, 2);
-        *buf.add(2) = *RADIX_100_TABLE.get_unchecked(mant as usize * 2 + 1);
 
-        buf = buf.add(
-            1 + ((if mant >= 10 { 1 } else { 0 }) & (if *buf.add(2) > b'0' { 1 } else { 0 })) * 2,
-        );
+        (exp, buf)
     }
-
-    (exp, buf)
 }
 
-pub(crate) unsafe fn write_f32(mant: u32, mut exp: i32, mut buf: *mut u8) -> *mut u8 {
-    let (exp_, buf_) = write_9_digits(mant, exp, buf);
-    exp = exp_;
-    buf = buf_;
-
-    if exp < 0 {
-        ptr::copy_nonoverlapping(b"E-".as_ptr(), buf, 2);
-        buf = buf.add(2);
-        exp = -exp;
-    } else {
-        ptr::copy_nonoverlapping(b"E".as_ptr(), buf, 1);
-        buf = buf.add(1);
-    }
+unchecked_fn! {
+    pub(crate) fn write_f32(mant: u32, mut exp: i32, mut buf: Cursor) -> Cursor {
+        let (exp_, buf_) = write_9_digits(mant, exp, buf);
+        exp = exp_;
+        buf = buf_;
 
-    if exp >= 10 {
-        write_2_digits(exp as u32, buf);
-        buf = buf.add(2);
-    } else {
-        write_1_digit(exp as u32, buf);
-        buf = buf.add(1);
-    }
+        if exp < 0 {
+            buf.write(b"E-");
+            buf = buf.add(2);
+            exp = -exp;
+        } else {
+            buf.write(b"E");
+            buf = buf.add(1);
+        }
 
-    buf
-}
+        if exp >= 10 {
+            write_2_digits(exp as u32, buf);
+            buf = buf.add(2);
+        } else {
+            write_1_digit(exp as u32, buf);
+            buf = buf.add(1);
+        }
 
-pub(crate) unsafe fn write_f64(mant: u64, mut exp: i32, mut buf: *mut u8) -> *mut u8 {
-    let lmant;
-    let rmant;
-
-    if mant >= 1_0000_0000 {
-        lmant = (mant / 1_0000_0000) as u32;
-        rmant = (mant as u32) - lmant.wrapping_mul(1_0000_0000);
-        exp += 8;
-    } else {
-        lmant = mant as u32;
-        rmant = 0;
+        buf
     }
+}
 
-    if rmant == 0 {
-        let (exp_, buf_) = write_9_digits(lmant, exp, buf);
-        exp = exp_;
-        buf = buf_;
-    } else {
-        if lmant >= 1_0000_0000 {
-            let mut prod = (lmant as u64) * 1441151882;
-            prod >>= 25;
-            ptr::copy_nonoverlapping(
-                RADIX_100_HEAD_TABLE
-                    .as_ptr()
-                    .add(((prod >> 32) as u32) as usize * 2),
-                buf,
-                2,
-            );
-
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(2));
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(4));
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(6));
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(8));
-
-            prod = (rmant as u64) * 281474978;
-            prod >>= 16;
-            prod += 1;
-            write_2_digits((prod >> 32) as u32, buf.add(10));
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(12));
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(14));
-            prod = ((prod as u32) as u64) * 100;
-            write_2_digits((prod >> 32) as u32, buf.add(16));
+unchecked_fn! {
+    pub(crate) fn write_f64(mant: u64, mut exp: i32, mut buf: Cursor) -> Cursor {
+        let lmant;
+        let rmant;
 
+        if mant >= 1_0000_0000 {
+            lmant = (mant / 1_0000_0000) as u32;
+            rmant = (mant as u32).wrapping_sub(lmant.wrapping_mul(1_0000_0000));
             exp += 8;
-            buf = buf.add(18);
         } else {
-            if lmant >= 100_0000 {
-                let mut prod = (lmant as u64) * 281474978;
-                prod >>= 16;
-                let head_digits = (prod >> 32) as u32;
-
-                ptr::copy_nonoverlapping(
-                    RADIX_100_HEAD_TABLE.as_ptr().add(head_digits as usize * 2),
-                    buf,
-                    2,
-                );
-                *buf.add(2) = *RADIX_100_TABLE.get_unchecked(head_digits as usize * 2 + 1);
+            lmant = mant as u32;
+            rmant = 0;
+        }
 
-                exp += 6 + (if head_digits >= 10 { 1 } else { 0 });
-                buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
+        if rmant == 0 {
+            let (exp_, buf_) = write_9_digits(lmant, exp, buf);
+            exp = exp_;
+            buf = buf_;
+        } else {
+            if lmant >= 1_0000_0000 {
+                let mut prod = (lmant as u64) * 1441151882;
+                prod >>= 25;
+                buf.copy_from(&RADIX_100_HEAD_TABLE, ((prod >> 32) as u32) as usize * 2, 2);
 
                 prod = ((prod as u32) as u64) * 100;
                 write_2_digits((prod >> 32) as u32, buf.add(2));
@@ -288,111 +238,236 @@ pub(crate) unsafe fn write_f64(mant: u64, mut exp: i32, mut buf: *mut u8) -> *mu
                 write_2_digits((prod >> 32) as u32, buf.add(4));
                 prod = ((prod as u32) as u64) * 100;
                 write_2_digits((prod >> 32) as u32, buf.add(6));
+                prod = ((prod as u32) as u64) * 100;
+                write_2_digits((prod >> 32) as u32, buf.add(8));
 
-                buf = buf.add(8);
-            } else if lmant >= 1_0000 {
-                let mut prod = (lmant as u64) * 429497;
-                let head_digits = (prod >> 32) as u32;
+                prod = (rmant as u64) * 281474978;
+                prod >>= 16;
+                prod += 1;
+                write_2_digits((prod >> 32) as u32, buf.add(10));
+                prod = ((prod as u32) as u64) * 100;
+                write_2_digits((prod >> 32) as u32, buf.add(12));
+                prod = ((prod as u32) as u64) * 100;
+                write_2_digits((prod >> 32) as u32, buf.add(14));
+                prod = ((prod as u32) as u64) * 100;
+                write_2_digits((prod >> 32) as u32, buf.add(16));
 
-                ptr::copy_nonoverlapping(
-                    RADIX_100_HEAD_TABLE.as_ptr().add(head_digits as usize * 2),
-                    buf,
-                    2,
-                );
-                *buf.add(2) = *RADIX_100_TABLE.get_unchecked(head_digits as usize * 2 + 1);
+                exp += 8;
+                buf = buf.add(18);
+            } else {
+                if lmant >= 100_0000 {
+                    let mut prod = (lmant as u64) * 281474978;
+                    prod >>= 16;
+                    let head_digits = (prod >> 32) as u32;
 
-                exp += 4 + (if head_digits >= 10 { 1 } else { 0 });
-                buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
+                    buf.copy_from(&RADIX_100_HEAD_TABLE, head_digits as usize * 2, 2);
+                    buf.set(2, get_unchecked!(RADIX_100_TABLE, head_digits as usize * 2 + 1));
 
-                prod = ((prod as u32) as u64) * 100;
-                write_2_digits((prod >> 32) as u32, buf.add(2));
-                prod = ((prod as u32) as u64) * 100;
-                write_2_digits((prod >> 32) as u32, buf.add(4));
+                    exp += 6 + (if head_digits >= 10 { 1 } else { 0 });
+                    buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
 
-                buf = buf.add(6);
-            } else if lmant >= 100 {
-                let mut prod = (lmant as u64) * 42949673;
-                let head_digits = (prod >> 32) as u32;
+                    prod = ((prod as u32) as u64) * 100;
+                    write_2_digits((prod >> 32) as u32, buf.add(2));
+                    prod = ((prod as u32) as u64) * 100;
+                    write_2_digits((prod >> 32) as u32, buf.add(4));
+                    prod = ((prod as u32) as u64) * 100;
+                    write_2_digits((prod >> 32) as u32, buf.add(6));
 
-                ptr::copy_nonoverlapping(
-                    RADIX_100_HEAD_TABLE.as_ptr().add(head_digits as usize * 2),
-                    buf,
-                    2,
-                );
-                *buf.add(2) = *RADIX_100_TABLE.get_unchecked(head_digits as usize * 2 + 1);
+                    buf = buf.add(8);
+                } else if lmant >= 1_0000 {
+                    let mut prod = (lmant as u64) * 429497;
+                    let head_digits = (prod >> 32) as u32;
 
-                exp += 2 + (if head_digits >= 10 { 1 } else { 0 });
-                buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
+                    buf.copy_from(&RADIX_100_HEAD_TABLE, head_digits as usize * 2, 2);
+                    buf.set(2, get_unchecked!(RADIX_100_TABLE, head_digits as usize * 2 + 1));
 
-                prod = ((prod as u32) as u64) * 100;
-                write_2_digits((prod >> 32) as u32, buf.add(2));
+                    exp += 4 + (if head_digits >= 10 { 1 } else { 0 });
+                    buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
 
-                buf = buf.add(4);
-            } else {
-                ptr::copy_nonoverlapping(
-                    RADIX_100_HEAD_TABLE.as_ptr().add(lmant as usize * 2),
-                    buf,
-                    2,
-                );
-                *buf.add(2) = *RADIX_100_TABLE.get_unchecked(lmant as usize * 2 + 1);
+                    prod = ((prod as u32) as u64) * 100;
+                    write_2_digits((prod >> 32) as u32, buf.add(2));
+                    prod = ((prod as u32) as u64) * 100;
+                    write_2_digits((prod >> 32) as u32, buf.add(4));
 
-                exp += if lmant >= 10 { 1 } else { 0 };
-                buf = buf.add(2 + (if lmant >= 10 { 1 } else { 0 }));
-            }
+                    buf = buf.add(6);
+                } else if lmant >= 100 {
+                    let mut prod = (lmant as u64) * 42949673;
+                    let head_digits = (prod >> 32) as u32;
 
-            let mut prod = (rmant as u64) * 281474978;
-            prod >>= 16;
-            prod += 1;
-            write_2_digits((prod >> 32) as u32, buf);
+                    buf.copy_from(&RADIX_100_HEAD_TABLE, head_digits as usize * 2, 2);
+                    buf.set(2, get_unchecked!(RADIX_100_TABLE, head_digits as usize * 2 + 1));
 
-            if prod as u32 <= (((1 as u64) << 32) / 100_0000) as u32 {
-                buf = buf.add(1 + (if *buf.add(1) > b'0' { 1 } else { 0 }));
-            } else {
-                prod = ((prod as u32) as u64) * 100;
-                write_2_digits((prod >> 32) as u32, buf.add(2));
+                    exp += 2 + (if head_digits >= 10 { 1 } else { 0 });
+                    buf = buf.add(if head_digits >= 10 { 1 } else { 0 });
 
-                if prod as u32 <= (((1 as u64) << 32) / 1_0000) as u32 {
-                    buf = buf.add(3 + (if *buf.add(3) > b'0' { 1 } else { 0 }));
+                    prod = ((prod as u32) as u64) * 100;
+                    write_2_digits((prod >> 32) as u32, buf.add(2));
+
+                    buf = buf.add(4);
+                } else {
+                    buf.copy_from(&RADIX_100_HEAD_TABLE, lmant as usize * 2, 2);
+                    buf.set(2, get_unchecked!(RADIX_100_TABLE, lmant as usize * 2 + 1));
+
+                    exp += if lmant >= 10 { 1 } else { 0 };
+                    buf = buf.add(2 + (if lmant >= 10 { 1 } else { 0 }));
+                }
+
+                let mut prod = (rmant as u64) * 281474978;
+                prod >>= 16;
+                prod += 1;
+                write_2_digits((prod >> 32) as u32, buf);
+
+                if prod as u32 <= (((1 as u64) << 32) / 100_0000) as u32 {
+                    buf = buf.add(1 + (if buf.get(1) > b'0' { 1 } else { 0 }));
                 } else {
                     prod = ((prod as u32) as u64) * 100;
-                    write_2_digits((prod >> 32) as u32, buf.add(4));
+                    write_2_digits((prod >> 32) as u32, buf.add(2));
 
-                    if prod as u32 <= (((1 as u64) << 32) / 100) as u32 {
-                        buf = buf.add(5 + (if *buf.add(5) > b'0' { 1 } else { 0 }));
+                    if prod as u32 <= (((1 as u64) << 32) / 1_0000) as u32 {
+                        buf = buf.add(3 + (if buf.get(3) > b'0' { 1 } else { 0 }));
                     } else {
                         prod = ((prod as u32) as u64) * 100;
-                        write_2_digits((prod >> 32) as u32, buf.add(6));
-                        buf = buf.add(7 + (if *buf.add(7) > b'0' { 1 } else { 0 }));
+                        write_2_digits((prod >> 32) as u32, buf.add(4));
+
+                        if prod as u32 <= (((1 as u64) << 32) / 100) as u32 {
+                            buf = buf.add(5 + (if buf.get(5) > b'0' { 1 } else { 0 }));
+                        } else {
+                            prod = ((prod as u32) as u64) * 100;
+                            write_2_digits((prod >> 32) as u32, buf.add(6));
+                            buf = buf.add(7 + (if buf.get(7) > b'0' { 1 } else { 0 }));
+                        }
                     }
                 }
             }
         }
+
+        if exp < 0 {
+            buf.write(b"E-");
+            buf = buf.add(2);
+            exp = -exp;
+        } else {
+            buf.write(b"E");
+            buf = buf.add(1);
+        }
+
+        if exp >= 100 {
+            let mut prod = (exp as u32) * 6554;
+            let d1 = prod >> 16;
+            prod = ((prod as u16) as u32) * 5;
+            let d2 = prod >> 15;
+            write_2_digits(d1, buf);
+            write_1_digit(d2, buf.add(2));
+            buf = buf.add(3);
+        } else if exp >= 10 {
+            write_2_digits(exp as u32, buf);
+            buf = buf.add(2);
+        } else {
+            write_1_digit(exp as u32, buf);
+            buf = buf.add(1);
+        }
+
+        buf
     }
+}
 
-    if exp < 0 {
-        ptr::copy_nonoverlapping(b"E-".as_ptr(), buf, 2);
-        buf = buf.add(2);
-        exp = -exp;
-    } else {
-        ptr::copy_nonoverlapping(b"E".as_ptr(), buf, 1);
-        buf = buf.add(1);
+fn decimal_length(mut n: u64) -> usize {
+    let mut len = 1;
+    while n >= 10000 {
+        n /= 10000;
+        len += 4;
     }
+    while n >= 100 {
+        n /= 100;
+        len += 2;
+    }
+    if n >= 10 {
+        len += 1;
+    }
+    len
+}
 
-    if exp >= 100 {
-        let mut prod = (exp as u32) * 6554;
-        let d1 = prod >> 16;
-        prod = ((prod as u16) as u32) * 5;
-        let d2 = prod >> 15;
-        write_2_digits(d1, buf);
-        write_1_digit(d2, buf.add(2));
-        buf = buf.add(3);
-    } else if exp >= 10 {
-        write_2_digits(exp as u32, buf);
-        buf = buf.add(2);
-    } else {
-        write_1_digit(exp as u32, buf);
-        buf = buf.add(1);
+unchecked_fn! {
+    fn write_digits(mut n: u64, len: usize, buf: Cursor) -> Cursor {
+        let end = buf.add(len);
+        let mut cur = end;
+        while cur.offset_from(buf) >= 2 {
+            cur = cur.sub(2);
+            write_2_digits((n % 100) as u32, cur);
+            n /= 100;
+        }
+        if cur.offset_from(buf) != 0 {
+            write_1_digit((n % 10) as u32, buf);
+        }
+        end
     }
+}
+
+unchecked_fn! {
+    pub(crate) fn write_u64(n: u64, buf: Cursor) -> Cursor {
+        write_digits(n, decimal_length(n), buf)
+    }
+}
+
+unchecked_fn! {
+    pub(crate) fn write_u128(n: u128, buf: Cursor) -> Cursor {
+        const DIV: u128 = 10_000_000_000_000_000_000;
+
+        if n <= u64::MAX as u128 {
+            return write_u64(n as u64, buf);
+        }
+        let low = (n % DIV) as u64;
+        let high = n / DIV;
+        let buf = if high <= u64::MAX as u128 {
+            write_u64(high as u64, buf)
+        } else {
+            let buf = write_u64((high / DIV) as u64, buf);
+            write_digits((high % DIV) as u64, 19, buf)
+        };
+        write_digits(low, 19, buf)
+    }
+}
+
+const SI_PREFIXES: [u8; 17] = [
+    b'y', b'z', b'a', b'f', b'p', b'n', b'u', b'm', 0, b'k', b'M', b'G', b'T', b'P', b'E', b'Z', b'Y',
+];
+
+unchecked_fn! {
+    pub(crate) fn write_engineering(mut mant: u64, mut exp: i32, si: bool, mut buf: Cursor) -> Cursor {
+        while mant.is_multiple_of(10) {
+            mant /= 10;
+            exp += 1;
+        }
+        let len = decimal_length(mant);
+        let sci_exp = exp + len as i32 - 1;
+        let eng_exp = sci_exp.div_euclid(3) * 3;
+        let int_len = (sci_exp - eng_exp + 1) as usize;
+
+        write_digits(mant, len, buf);
+        if len <= int_len {
+            buf.add(len).fill(b'0', int_len - len);
+            buf = buf.add(int_len);
+        } else {
+            buf.add(int_len).copy_to(buf.add(int_len + 1), len - int_len);
+            buf.set(int_len, b'.');
+            buf = buf.add(len + 1);
+        }
 
-    buf
+        if si && (-24..=24).contains(&eng_exp) {
+            let prefix = SI_PREFIXES[(eng_exp / 3 + 8) as usize];
+            if prefix != 0 {
+                buf.set(0, prefix);
+                buf = buf.add(1);
+            }
+            return buf;
+        }
+
+        if eng_exp < 0 {
+            buf.write(b"E-");
+            buf = buf.add(2);
+        } else {
+            buf.write(b"E");
+            buf = buf.add(1);
+        }
+        write_u64(eng_exp.unsigned_abs() as u64, buf)
+    }
 }
@@ -0,0 +1,56 @@
+// Indexing for lookups whose index the caller has already bounded, such as
+// a power table read with an exponent that passed a range check. Without
+// the `safe` feature the read is unchecked and has to be inside `unchecked!`;
+// with it the bound is checked again like any other index. Both forms work
+// in const fns.
+#[cfg(not(feature = "safe"))]
+macro_rules! get_unchecked {
+    ($table:expr, $index:expr) => {{
+        let index: usize = $index;
+        debug_assert!(index < $table.len());
+        *$table.as_ptr().add(index)
+    }};
+}
+
+#[cfg(feature = "safe")]
+macro_rules! get_unchecked {
+    ($table:expr, $index:expr) => {
+        $table[$index]
+    };
+}
+
+// Code that calls the unchecked accessors: an `unsafe` block by default, and
+// a plain block with the `safe` feature, where the accessors check. Every use
+// carries a `SAFETY:` comment for the default build.
+#[cfg(not(feature = "safe"))]
+macro_rules! unchecked {
+    ($($body:tt)*) => {
+        unsafe { $($body)* }
+    };
+}
+
+#[cfg(feature = "safe")]
+macro_rules! unchecked {
+    ($($body:tt)*) => {
+        { $($body)* }
+    };
+}
+
+// A function whose caller has to provide the room or the bound for the
+// unchecked accessors it uses: an `unsafe fn` by default, and a safe one with
+// the `safe` feature.
+#[cfg(not(feature = "safe"))]
+macro_rules! unchecked_fn {
+    ($(#[$attr:meta])* $vis:vis fn $($item:tt)*) => {
+        $(#[$attr])* $vis unsafe fn $($item)*
+    };
+}
+
+#[cfg(feature = "safe")]
+macro_rules! unchecked_fn {
+    ($(#[$attr:meta])* $vis:vis fn $($item:tt)*) => {
+        $(#[$attr])* $vis fn $($item)*
+    };
+}
+
+pub(crate) use {get_unchecked, unchecked, unchecked_fn};